@@ -9,6 +9,7 @@ mod extrude;
 mod geometry;
 mod mesh;
 mod primitives;
+mod simplify;
 
 pub use bounds::*;
 pub use collection::*;
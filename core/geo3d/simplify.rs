@@ -0,0 +1,414 @@
+// Copyright © 2025 The µcad authors <info@ucad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Quadric-error mesh simplification (Garland–Heckbert edge decimation).
+//!
+//! CSG output from `manifold` can carry far more triangles than needed for
+//! preview or export, so [`TriangleMesh::simplify`] and
+//! [`TriangleMesh::simplify_to_max_error`] decimate a mesh by repeatedly
+//! collapsing the cheapest edge, using the accumulated vertex quadrics to
+//! both rank candidates and place the resulting vertex.
+
+use cgmath::{InnerSpace, Matrix4, SquareMatrix, Vector3, Vector4};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::*;
+
+/// Symmetric 4×4 quadric error matrix `Q = Σ K_p`.
+type Quadric = Matrix4<f64>;
+
+/// Weight applied to the synthetic constraint plane of a boundary edge,
+/// making collapses that would tear open the mesh prohibitively expensive.
+const BOUNDARY_WEIGHT: f64 = 1e3;
+
+/// Outer product `p pᵀ` of a plane equation `p = (a, b, c, d)`.
+fn plane_quadric(p: Vector4<f64>) -> Quadric {
+    Matrix4::new(
+        p.x * p.x,
+        p.x * p.y,
+        p.x * p.z,
+        p.x * p.w,
+        p.y * p.x,
+        p.y * p.y,
+        p.y * p.z,
+        p.y * p.w,
+        p.z * p.x,
+        p.z * p.y,
+        p.z * p.z,
+        p.z * p.w,
+        p.w * p.x,
+        p.w * p.y,
+        p.w * p.z,
+        p.w * p.w,
+    )
+}
+
+/// Quadric of the plane through `p0, p1, p2`, or a zero quadric for a degenerate face.
+fn face_quadric(p0: Vec3, p1: Vec3, p2: Vec3) -> Quadric {
+    let normal = (p1 - p0).cross(p2 - p0);
+    let len = normal.magnitude();
+    if len < Scalar::EPSILON {
+        return Matrix4::from_value(0.0);
+    }
+    let n = normal / len;
+    plane_quadric(Vector4::new(n.x, n.y, n.z, -n.dot(p0)))
+}
+
+/// Error `v̄ᵀ Q v̄` of placing a vertex at `v̄`.
+fn quadric_error(q: &Quadric, v: Vec3) -> f64 {
+    let v4 = Vector4::new(v.x, v.y, v.z, 1.0);
+    v4.dot(q * v4)
+}
+
+/// Position minimizing `v̄ᵀ Q v̄`, falling back to `fallback` when the quadric's
+/// 3×3 block is singular (the usual case is a near-flat quadric).
+fn optimal_position(q: &Quadric, fallback: Vec3) -> Vec3 {
+    let a = Mat3::new(
+        q.x.x, q.x.y, q.x.z, q.y.x, q.y.y, q.y.z, q.z.x, q.z.y, q.z.z,
+    );
+    let b = Vector3::new(q.w.x, q.w.y, q.w.z);
+    match a.invert() {
+        Some(inv) => inv * -b,
+        None => fallback,
+    }
+}
+
+/// A candidate edge collapse, ordered cheapest-first in a [`BinaryHeap`].
+struct Candidate {
+    cost: f64,
+    v1: u32,
+    v2: u32,
+    target: Vec3,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Reversed so that `BinaryHeap` (a max-heap) pops the *cheapest* edge first.
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// Mutable decimation state shared by [`TriangleMesh::simplify`] and
+/// [`TriangleMesh::simplify_to_max_error`].
+struct Decimator {
+    positions: Vec<Vec3>,
+    alive_vertex: Vec<bool>,
+    quadrics: Vec<Quadric>,
+    faces: Vec<[u32; 3]>,
+    alive_face: Vec<bool>,
+    vertex_faces: Vec<HashSet<usize>>,
+    adjacency: Vec<HashSet<u32>>,
+}
+
+impl Decimator {
+    fn new(mesh: &TriangleMesh) -> Self {
+        let n = mesh.vertices.len();
+        let faces: Vec<[u32; 3]> = mesh
+            .triangle_indices
+            .iter()
+            .map(|t| [t.0, t.1, t.2])
+            .collect();
+
+        let mut vertex_faces = vec![HashSet::new(); n];
+        let mut adjacency = vec![HashSet::new(); n];
+        let mut edge_faces: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+
+        for (i, face) in faces.iter().enumerate() {
+            for &v in face {
+                vertex_faces[v as usize].insert(i);
+            }
+            for &(a, b) in &[(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+                adjacency[a as usize].insert(b);
+                adjacency[b as usize].insert(a);
+                let key = if a < b { (a, b) } else { (b, a) };
+                edge_faces.entry(key).or_default().push(i);
+            }
+        }
+
+        let positions: Vec<Vec3> = mesh.vertices.iter().map(|v| v.pos).collect();
+        let mut quadrics = vec![Matrix4::from_value(0.0); n];
+
+        for face in &faces {
+            let q = face_quadric(
+                positions[face[0] as usize],
+                positions[face[1] as usize],
+                positions[face[2] as usize],
+            );
+            for &v in face {
+                quadrics[v as usize] = quadrics[v as usize] + q;
+            }
+        }
+
+        // Pin down boundary edges with a synthetic constraint plane so open
+        // borders aren't chewed away by the decimation.
+        for (&(a, b), owners) in edge_faces.iter() {
+            if owners.len() != 1 {
+                continue;
+            }
+            let face = faces[owners[0]];
+            let third = face
+                .iter()
+                .copied()
+                .find(|&v| v != a && v != b)
+                .unwrap_or(a);
+            let face_normal =
+                face_quadric_normal(positions[a as usize], positions[b as usize], positions[third as usize]);
+            let edge_dir = positions[b as usize] - positions[a as usize];
+            let len = edge_dir.magnitude();
+            if len < Scalar::EPSILON {
+                continue;
+            }
+            let n = edge_dir.cross(face_normal).normalize();
+            let p = Vector4::new(n.x, n.y, n.z, -n.dot(positions[a as usize]));
+            let penalty = plane_quadric(p) * BOUNDARY_WEIGHT;
+            quadrics[a as usize] = quadrics[a as usize] + penalty;
+            quadrics[b as usize] = quadrics[b as usize] + penalty;
+        }
+
+        let alive_face = vec![true; faces.len()];
+
+        Self {
+            positions,
+            alive_vertex: vec![true; n],
+            quadrics,
+            faces,
+            alive_face,
+            vertex_faces,
+            adjacency,
+        }
+    }
+
+    fn triangle_count(&self) -> usize {
+        self.alive_face.iter().filter(|&&a| a).count()
+    }
+
+    fn candidate(&self, v1: u32, v2: u32) -> Candidate {
+        let q = self.quadrics[v1 as usize] + self.quadrics[v2 as usize];
+        let midpoint = (self.positions[v1 as usize] + self.positions[v2 as usize]) / 2.0;
+        let target = optimal_position(&q, midpoint);
+        Candidate {
+            cost: quadric_error(&q, target),
+            v1,
+            v2,
+            target,
+        }
+    }
+
+    fn build_heap(&self) -> BinaryHeap<Candidate> {
+        let mut heap = BinaryHeap::new();
+        for (v1, neighbors) in self.adjacency.iter().enumerate() {
+            for &v2 in neighbors {
+                if (v1 as u32) < v2 {
+                    heap.push(self.candidate(v1 as u32, v2));
+                }
+            }
+        }
+        heap
+    }
+
+    /// Collapse `v2` into `v1`, moving `v1` to `target`.
+    fn collapse(&mut self, v1: u32, v2: u32, target: Vec3, heap: &mut BinaryHeap<Candidate>) {
+        self.positions[v1 as usize] = target;
+        self.quadrics[v1 as usize] = self.quadrics[v1 as usize] + self.quadrics[v2 as usize];
+        self.alive_vertex[v2 as usize] = false;
+
+        for f in std::mem::take(&mut self.vertex_faces[v2 as usize]) {
+            if !self.alive_face[f] {
+                continue;
+            }
+            let face = &mut self.faces[f];
+            for slot in face.iter_mut() {
+                if *slot == v2 {
+                    *slot = v1;
+                }
+            }
+            if face[0] == face[1] || face[1] == face[2] || face[2] == face[0] {
+                self.alive_face[f] = false;
+            } else {
+                self.vertex_faces[v1 as usize].insert(f);
+            }
+        }
+
+        let neighbors_of_v2: Vec<u32> = self.adjacency[v2 as usize].iter().copied().collect();
+        for w in neighbors_of_v2 {
+            self.adjacency[w as usize].remove(&v2);
+            if w != v1 {
+                self.adjacency[w as usize].insert(v1);
+                self.adjacency[v1 as usize].insert(w);
+            }
+        }
+        self.adjacency[v1 as usize].remove(&v2);
+        self.adjacency[v2 as usize].clear();
+
+        for &w in &self.adjacency[v1 as usize] {
+            if self.alive_vertex[w as usize] {
+                heap.push(self.candidate(v1.min(w), v1.max(w)));
+            }
+        }
+    }
+
+    /// Rebuild a [`TriangleMesh`] from the surviving vertices/faces and recompute normals.
+    fn finish(self) -> TriangleMesh {
+        let mut new_index = vec![u32::MAX; self.positions.len()];
+        let mut vertices = Vec::new();
+        for (i, alive) in self.alive_vertex.iter().enumerate() {
+            if *alive {
+                new_index[i] = vertices.len() as u32;
+                vertices.push(Vertex {
+                    pos: self.positions[i],
+                    normal: Vec3::new(0.0, 0.0, 0.0),
+                });
+            }
+        }
+
+        let mut triangle_indices = Vec::new();
+        for (f, alive) in self.faces.iter().zip(self.alive_face.iter()) {
+            if !*alive {
+                continue;
+            }
+            let tri = Triangle(new_index[f[0] as usize], new_index[f[1] as usize], new_index[f[2] as usize]);
+            if !tri.is_degenerated() {
+                triangle_indices.push(tri);
+            }
+        }
+
+        let mut mesh = TriangleMesh {
+            vertices,
+            triangle_indices,
+        };
+        mesh.recompute_normals();
+        mesh
+    }
+}
+
+/// Unit normal of the face `p0, p1, p2` (`+Z` for a degenerate face).
+fn face_quadric_normal(p0: Vec3, p1: Vec3, p2: Vec3) -> Vec3 {
+    let n = (p1 - p0).cross(p2 - p0);
+    let len = n.magnitude();
+    if len < Scalar::EPSILON {
+        Vec3::new(0.0, 0.0, 1.0)
+    } else {
+        n / len
+    }
+}
+
+impl TriangleMesh {
+    /// Decimate the mesh using quadric-error edge collapse until the triangle
+    /// count reaches `target_ratio` of the original (clamped to `[0.0, 1.0]`).
+    pub fn simplify(&self, target_ratio: f64) -> Self {
+        let target_ratio = target_ratio.clamp(0.0, 1.0);
+        let target_count =
+            ((self.triangle_indices.len() as f64) * target_ratio).round() as usize;
+        self.simplify_impl(target_count, f64::INFINITY)
+    }
+
+    /// Decimate the mesh, collapsing edges only while their quadric error
+    /// stays at or below `max_error`.
+    pub fn simplify_to_max_error(&self, max_error: f64) -> Self {
+        self.simplify_impl(0, max_error)
+    }
+
+    fn simplify_impl(&self, target_count: usize, max_error: f64) -> Self {
+        if self.triangle_indices.is_empty() {
+            return self.clone();
+        }
+
+        let mut decimator = Decimator::new(self);
+        let mut heap = decimator.build_heap();
+
+        while decimator.triangle_count() > target_count {
+            let Some(candidate) = heap.pop() else {
+                break;
+            };
+            if !decimator.alive_vertex[candidate.v1 as usize]
+                || !decimator.alive_vertex[candidate.v2 as usize]
+                || !decimator.adjacency[candidate.v1 as usize].contains(&candidate.v2)
+            {
+                continue;
+            }
+            if candidate.cost > max_error {
+                break;
+            }
+            decimator.collapse(candidate.v1, candidate.v2, candidate.target, &mut heap);
+        }
+
+        decimator.finish()
+    }
+
+    /// Recompute per-vertex normals as the area-weighted average of adjacent face normals.
+    pub fn recompute_normals(&mut self) {
+        for v in &mut self.vertices {
+            v.normal = Vec3::new(0.0, 0.0, 0.0);
+        }
+        for tri in &self.triangle_indices {
+            let face = self.fetch_triangle(*tri);
+            let weighted_normal = face.normal();
+            self.vertices[tri.0 as usize].normal += weighted_normal;
+            self.vertices[tri.1 as usize].normal += weighted_normal;
+            self.vertices[tri.2 as usize].normal += weighted_normal;
+        }
+        for v in &mut self.vertices {
+            if v.normal.magnitude() > Scalar::EPSILON {
+                v.normal = v.normal.normalize();
+            }
+        }
+    }
+}
+
+#[test]
+fn test_simplify_reduces_triangle_count() {
+    let manifold = Manifold::sphere(1.0, 512);
+    let mesh = TriangleMesh::from(manifold.to_mesh());
+    let original_count = mesh.triangle_indices.len();
+
+    let simplified = mesh.simplify(0.1);
+
+    assert!(simplified.triangle_indices.len() < original_count);
+    assert!(!simplified.vertices.is_empty());
+    // Volume should be roughly preserved by a moderate decimation.
+    assert!((simplified.volume() - mesh.volume()).abs() / mesh.volume() < 0.2);
+}
+
+#[test]
+fn test_simplify_to_max_error_keeps_flat_mesh() {
+    // Two coplanar triangles sharing an edge: every edge touches the open border, so the
+    // boundary penalty makes every collapse far costlier than a tiny error budget allows,
+    // and the mesh is kept as it is.
+    let mesh = TriangleMesh {
+        vertices: vec![
+            Vertex {
+                pos: Vec3::new(0.0, 0.0, 0.0),
+                normal: Vec3::new(0.0, 0.0, 1.0),
+            },
+            Vertex {
+                pos: Vec3::new(1.0, 0.0, 0.0),
+                normal: Vec3::new(0.0, 0.0, 1.0),
+            },
+            Vertex {
+                pos: Vec3::new(1.0, 1.0, 0.0),
+                normal: Vec3::new(0.0, 0.0, 1.0),
+            },
+            Vertex {
+                pos: Vec3::new(0.0, 1.0, 0.0),
+                normal: Vec3::new(0.0, 0.0, 1.0),
+            },
+        ],
+        triangle_indices: vec![Triangle(0, 1, 2), Triangle(0, 2, 3)],
+    };
+
+    let simplified = mesh.simplify_to_max_error(1e-9);
+    assert_eq!(simplified.triangle_indices.len(), mesh.triangle_indices.len());
+}
@@ -6,6 +6,7 @@
 pub mod ply;
 pub mod stl;
 pub mod svg;
+pub mod yaml;
 
 use microcad_lang::{model_tree::*, syntax::*, value::*};
 use thiserror::Error;
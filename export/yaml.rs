@@ -1,44 +1,89 @@
 use std::{fs::File, path::PathBuf};
 
-use microcad_render::Node;
+use microcad_lang::model_tree::*;
 
 use crate::*;
 
+/// Snapshot `node` with [`SerializedModel`] and write it to `filename` through `write`.
+fn export_model_tree(
+    node: ModelNode,
+    filename: &std::path::Path,
+    write: impl FnOnce(std::io::BufWriter<File>, &SerializedModel) -> Result<(), ExportError>,
+) -> Result<Value, ExportError> {
+    let snapshot = SerializedModel::from_model_node(&node);
+    let file = File::create(filename)?;
+    write(std::io::BufWriter::new(file), &snapshot)?;
+    Ok(Value::None)
+}
+
+/// Read back the *structure* (id, nesting) of a model tree previously written by
+/// [`YamlExporter`] or [`JsonExporter`]. The result has no geometry and cannot be
+/// rendered; see [`microcad_lang::model_tree::SerializedModel::to_model_node`].
+pub fn import_model_tree_yaml(filename: &std::path::Path) -> std::io::Result<ModelNode> {
+    let snapshot: SerializedModel = serde_yaml::from_reader(std::io::BufReader::new(File::open(filename)?))
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    Ok(snapshot.to_model_node())
+}
+
+/// Read back the *structure* of a model tree previously written by [`JsonExporter`].
+/// The result has no geometry and cannot be rendered; see
+/// [`microcad_lang::model_tree::SerializedModel::to_model_node`].
+pub fn import_model_tree_json(filename: &std::path::Path) -> std::io::Result<ModelNode> {
+    let snapshot: SerializedModel = serde_json::from_reader(std::io::BufReader::new(File::open(filename)?))
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    Ok(snapshot.to_model_node())
+}
+
+/// Exports the structured tree of a [`ModelNode`] (id, element, attributes,
+/// origin, resolved output type, children) as YAML, instead of writing
+/// `{:?}` of each node indented by depth. This is a read-only inspection
+/// dump, not a render cache: [`import_model_tree_yaml`] reads back the
+/// tree's structure only, not its geometry.
 pub struct YamlExporter {
     filename: PathBuf,
 }
 
-impl Exporter for YamlExporter {
-    fn from_settings(settings: &ExportSettings) -> microcad_core::Result<Self>
-    where
-        Self: Sized,
-    {
-        assert!(settings.filename().is_some());
-
-        Ok(Self {
-            filename: PathBuf::from(settings.filename().unwrap()),
-        })
+impl YamlExporter {
+    /// Create a new exporter writing to `filename`.
+    pub fn new(filename: PathBuf) -> Self {
+        Self { filename }
     }
+}
 
-    fn export(&mut self, node: Node) -> microcad_core::Result<()> {
-        let file = File::create(&self.filename)?;
-        let mut writer = std::io::BufWriter::new(&file);
+impl Exporter for YamlExporter {
+    fn id() -> &'static str {
+        "yaml"
+    }
 
-        use std::io::Write;
+    fn export(&mut self, node: ModelNode) -> Result<Value, ExportError> {
+        export_model_tree(node, &self.filename, |writer, snapshot| {
+            serde_yaml::to_writer(writer, snapshot)
+                .map_err(|err| ExportError::IoError(std::io::Error::other(err)))
+        })
+    }
+}
 
-        use microcad_core::render::tree::Depth;
+/// Same structured export as [`YamlExporter`], but as JSON.
+pub struct JsonExporter {
+    filename: PathBuf,
+}
 
-        for child in node.descendants() {
-            for _ in 0..child.depth() {
-                write!(writer, "  ")?;
-            }
-            writeln!(writer, "- {:?}", child.borrow())?;
-        }
+impl JsonExporter {
+    /// Create a new exporter writing to `filename`.
+    pub fn new(filename: PathBuf) -> Self {
+        Self { filename }
+    }
+}
 
-        Ok(())
+impl Exporter for JsonExporter {
+    fn id() -> &'static str {
+        "json"
     }
 
-    fn file_extensions(&self) -> Vec<&str> {
-        vec!["yaml"]
+    fn export(&mut self, node: ModelNode) -> Result<Value, ExportError> {
+        export_model_tree(node, &self.filename, |writer, snapshot| {
+            serde_json::to_writer_pretty(writer, snapshot)
+                .map_err(|err| ExportError::IoError(std::io::Error::other(err)))
+        })
     }
 }
@@ -35,8 +35,10 @@ impl Eval<ArgumentValueList> for ArgumentList {
 
 impl Eval for Call {
     fn eval(&self, context: &mut EvalContext) -> EvalResult<Value> {
-        // find self in symbol table by own name
-        let symbol = match context.lookup(&self.name) {
+        // find self in symbol table by own name: a call/instantiation always refers to
+        // something callable, so restrict the lookup to `SymbolNamespace::Entity` (this
+        // lets e.g. a property and a workbench share a name without colliding here).
+        let symbol = match context.lookup_in_namespace(&self.name, SymbolNamespace::Entity) {
             Ok(symbol) => symbol,
             Err(err) => {
                 context.error(self, err)?;
@@ -32,6 +32,9 @@ pub struct Context {
     exporters: ExporterRegistry,
     /// Importer registry.
     importers: ImporterRegistry,
+    /// Number of fragments evaluated so far by [`Context::eval_fragment`], used to give
+    /// each one a unique module name so later fragments don't collide with earlier ones.
+    fragment_count: usize,
 }
 
 impl Context {
@@ -113,6 +116,42 @@ impl Context {
         source_file.eval(self)
     }
 
+    /// Evaluate a single fragment of source code within a persistent, REPL-style session.
+    ///
+    /// Unlike [`Context::eval`], which evaluates one whole, already-resolved source file and
+    /// is meant to be called once, `eval_fragment` is meant to be called repeatedly on the
+    /// same `Context`: `symbol_table` and `stack` both survive between calls, so workbenches,
+    /// `use` imports and constants entered by earlier fragments stay visible to later ones.
+    ///
+    /// Each fragment is parsed into its own [`SourceFile`], named `<fragment N>` and inserted
+    /// into the symbol table under that name so it never collides with an earlier one, and
+    /// only the symbols it newly defines are resolved into the existing symbol table -- the
+    /// rest of the table is left untouched. The fragment's statements are then evaluated in
+    /// the persisted root stack frame, which is opened lazily on the first call and kept open
+    /// for the lifetime of the session.
+    ///
+    /// A parse or resolve error, or an error that evaluation returns, is returned from this
+    /// call as `Err` and is not pushed as a diagnostic; only errors that evaluation itself
+    /// reports as diagnostics end up in the diagnostic handler. Either way the error does
+    /// not poison the session: the next call to `eval_fragment` still sees everything
+    /// defined so far.
+    pub fn eval_fragment(&mut self, src: &str) -> EvalResult<Value> {
+        let id = Identifier::no_ref(&format!("<fragment {}>", self.fragment_count));
+        let name = QualifiedName::no_ref(vec![id.clone()]);
+        self.fragment_count += 1;
+
+        let source_file = Rc::new(SourceFile::parse_fragment(name, src)?);
+        let symbol = source_file.resolve(None);
+        // a fragment has no file name, so `symbol.id()` is empty: key it by its fragment id
+        self.symbol_table.insert_symbol(id, symbol.clone())?;
+
+        if self.stack.current_frame().is_none() {
+            self.open(StackFrame::Source(symbol.id(), SymbolMap::default()));
+        }
+
+        source_file.statements.eval(self)
+    }
+
     /// Run the closure `f` within the given `stack_frame`.
     pub fn scope<T>(&mut self, stack_frame: StackFrame, f: impl FnOnce(&mut Context) -> T) -> T {
         self.open(stack_frame);
@@ -283,7 +322,7 @@ impl Context {
         )
     }
 }
-/*
+
 impl UseSymbol for Context {
     fn use_symbol(
         &mut self,
@@ -295,23 +334,22 @@ impl UseSymbol for Context {
         log::debug!("Using symbol {name:?}");
 
         let symbol = self.lookup(name)?;
+        let id = id.unwrap_or_else(|| symbol.id());
+        let symbol = symbol.clone_with_visibility(visibility);
+
         if self.is_module() {
-            let id = id.clone().unwrap_or(symbol.id());
-            let symbol = symbol.clone_with_visibility(visibility);
             if within.is_empty() {
-                self.symbols.insert(id, symbol);
+                self.symbol_table.insert_imported(id.clone(), symbol.clone());
             } else {
-                self.symbols
+                self.symbol_table
                     .search(within)?
-                    .borrow_mut()
-                    .children
-                    .insert(id, symbol);
+                    .insert_imported_child(id.clone(), symbol.clone());
             }
-            log::trace!("Symbol Table:\n{}", self.symbols);
+            log::trace!("Symbol Table:\n{}", self.symbol_table);
         }
 
         if self.is_code() {
-            self.stack.put_local(id, symbol.clone())?;
+            self.stack.put_imported_local(Some(id), symbol.clone())?;
             log::trace!("Local Stack:\n{}", self.stack);
         }
 
@@ -328,35 +366,34 @@ impl UseSymbol for Context {
 
         let symbol = self.lookup(name)?;
         if symbol.is_empty() {
-            Err(EvalError::NoSymbolsToUse(symbol.full_name()))
-        } else {
+            return Err(EvalError::NoSymbolsToUse(symbol.full_name()));
+        }
+
+        symbol.with_children(|(id, child)| {
+            let child = child.clone_as_glob_import(visibility);
+
             if self.is_module() {
-                for (id, symbol) in symbol.borrow().children.iter() {
-                    let symbol = symbol.clone_with_visibility(visibility);
-                    if within.is_empty() {
-                        self.symbols.insert(id.clone(), symbol);
-                    } else {
-                        self.symbols
-                            .search(within)?
-                            .borrow_mut()
-                            .children
-                            .insert(id.clone(), symbol);
-                    }
+                if within.is_empty() {
+                    self.symbol_table.insert_imported(id.clone(), child.clone());
+                } else {
+                    self.symbol_table
+                        .search(within)?
+                        .insert_imported_child(id.clone(), child.clone());
                 }
-                log::trace!("Symbol Table:\n{}", self.symbols);
             }
 
             if self.is_code() {
-                for (id, symbol) in symbol.borrow().children.iter() {
-                    self.stack.put_local(Some(id.clone()), symbol.clone())?;
-                }
-                log::trace!("Local Stack:\n{}", self.stack);
+                self.stack.put_imported_local(Some(id.clone()), child)?;
             }
-            Ok(symbol)
-        }
+
+            Ok::<_, EvalError>(())
+        })?;
+        log::trace!("Symbol Table:\n{}", self.symbol_table);
+        log::trace!("Local Stack:\n{}", self.stack);
+
+        Ok(symbol)
     }
 }
-*/
 impl Locals for Context {
     fn set_local_value(&mut self, id: Identifier, value: Value) -> EvalResult<()> {
         self.stack.set_local_value(id, value)
@@ -395,34 +432,55 @@ impl Default for Context {
             output: Box::new(Stdout),
             exporters: Default::default(),
             importers: Default::default(),
+            fragment_count: 0,
         }
     }
 }
 
-impl Lookup<EvalError> for Context {
-    fn lookup(&self, name: &QualifiedName) -> EvalResult<Symbol> {
+impl Context {
+    /// Shared implementation behind [`Lookup::lookup`] and [`Lookup::lookup_in_namespace`].
+    ///
+    /// Origins are tried in *all* scopes at once, but they don't count equally: each one is
+    /// tagged with a precedence rank mirroring lexical scoping (inner bindings shadow outer
+    /// ones), from innermost to outermost: `local` (stack) → `workbench`/`property` → `module`
+    /// → `global`. Only the candidates at the single best-ranked level that produced anything
+    /// are considered; a `local` hit silently shadows a conflicting `global` one instead of
+    /// raising [`EvalError::AmbiguousSymbol`]. Ambiguity is only reported when two candidates
+    /// tie at that same winning level (e.g. two glob-imported symbols).
+    ///
+    /// When `namespace` is `Some`, candidates whose [`Symbol::namespace`] resolves to the
+    /// other [`SymbolNamespace`] are dropped before the precedence ranks are compared, so
+    /// e.g. a property and a workbench sharing a name never collide or shadow each other
+    /// when the call site only ever wanted one of the two namespaces.
+    fn lookup_filtered(
+        &self,
+        name: &QualifiedName,
+        namespace: Option<SymbolNamespace>,
+    ) -> EvalResult<Symbol> {
         log::debug!("Lookup symbol '{name:?}' (at line {:?}):", name.src_ref());
 
         let name = &self.symbol_table.de_alias(name);
 
         log::trace!("- lookups -------------------------------------------------------");
-        // collect all symbols that can be found and remember origin
+        // collect all symbols that can be found, tagged with their origin and precedence rank
         let result = [
             (
+                0,
                 "local",
                 match self.stack.lookup(name) {
-                    Ok(SymbolOrName::Name(name)) => self.lookup(&name),
+                    Ok(SymbolOrName::Name(name)) => self.lookup_filtered(&name, namespace),
                     Ok(SymbolOrName::Symbol(symbol)) => Ok(symbol),
                     Err(err) => Err(err),
                 },
             ),
+            (1, "workbench", Ok(self.lookup_workbench(name)?)),
+            (1, "property", self.lookup_property(name)),
             (
+                2,
                 "module",
                 self.lookup_within(name, self.stack.current_module_name()),
             ),
-            ("property", self.lookup_property(name)),
-            ("workbench", Ok(self.lookup_workbench(name)?)),
-            ("global", Ok(self.symbol_table.lookup(name)?)),
+            (3, "global", Ok(self.symbol_table.lookup(name)?)),
         ]
         .into_iter();
 
@@ -432,19 +490,25 @@ impl Lookup<EvalError> for Context {
         // collect ok-results and ambiguity errors
         let (found, mut ambiguous) = result.fold(
             (Vec::new(), Vec::new()),
-            |(mut oks, mut ambiguity), (origin, r)| {
+            |(mut oks, mut ambiguity), (rank, origin, r)| {
                 match r {
-                    Ok(symbol) => oks.push((origin, symbol)),
-                    Err(EvalError::AmbiguousSymbol { ambiguous, others }) => {
-                        ambiguity.push((origin, EvalError::AmbiguousSymbol { ambiguous, others }))
-                    }
-                    Err(
-                        EvalError::SymbolNotFound(_)
-                        | EvalError::ResolveError(ResolveError::SymbolNotFound(_))
-                        | EvalError::LocalNotFound(_)
-                        | EvalError::ResolveError(ResolveError::ExternalPathNotFound(_))
-                        | EvalError::ResolveError(ResolveError::NulHash),
-                    ) => (),
+                    // a candidate from the other namespace is dropped before ranking, so it
+                    // can't shadow a worse-ranked candidate of the requested namespace
+                    Ok(symbol)
+                        if namespace.is_some_and(|namespace| {
+                            self.symbol_table
+                                .follow_alias(&symbol)
+                                .ok()
+                                .and_then(|symbol| symbol.namespace())
+                                .is_some_and(|ns| ns != namespace)
+                        }) => {}
+                    Ok(symbol) => oks.push((rank, origin, symbol)),
+                    Err(EvalError::AmbiguousSymbol { ambiguous, others }) => ambiguity.push((
+                        rank,
+                        origin,
+                        EvalError::AmbiguousSymbol { ambiguous, others },
+                    )),
+                    Err(err) if is_benign_not_found(&err) => (),
                     Err(err) => errors.push((origin, err)),
                 }
                 (oks, ambiguity)
@@ -461,24 +525,45 @@ impl Lookup<EvalError> for Context {
             return Err(errors.remove(0).1);
         }
 
-        // early emit any ambiguity error
-        if !ambiguous.is_empty() {
+        // the best (lowest) precedence rank that produced anything at all: every candidate
+        // at a worse rank is shadowed and never even reaches the ambiguity check below
+        let min_rank = match found
+            .iter()
+            .map(|(rank, ..)| *rank)
+            .chain(ambiguous.iter().map(|(rank, ..)| *rank))
+            .min()
+        {
+            Some(min_rank) => min_rank,
+            None => {
+                log::debug!(
+                    "{not_found} Symbol '{name:?}'",
+                    not_found = crate::mark!(NOT_FOUND_INTERIM)
+                );
+                return match self.suggest_candidate(name) {
+                    Some(suggestion) => Err(EvalError::SymbolNotFoundSuggestion {
+                        name: name.clone(),
+                        suggestion,
+                    }),
+                    None => Err(EvalError::SymbolNotFound(name.clone())),
+                };
+            }
+        };
+
+        // an ambiguity raised *at the winning rank* is a real tie (e.g. two glob-imported
+        // symbols at module level): a worse-ranked candidate must not silently win instead
+        if let Some((_, _, err)) = ambiguous.into_iter().find(|(rank, ..)| *rank == min_rank) {
             log::debug!(
-                "{ambiguous} Symbol '{name:?}':\n{}",
-                ambiguous
-                    .iter()
-                    .map(|(origin, err)| format!("{origin}: {err}"))
-                    .collect::<Vec<_>>()
-                    .join("\n"),
+                "{ambiguous} Symbol '{name:?}' at rank {min_rank}: {err}",
                 ambiguous = crate::mark!(AMBIGUOUS)
             );
-            return Err(ambiguous.remove(0).1);
+            return Err(err);
         }
 
-        // follow aliases
+        // follow aliases, keeping only the candidates that shadow everything else
         let found: Vec<_> = found
             .into_iter()
-            .filter_map(|(origin, symbol)| {
+            .filter(|(rank, ..)| *rank == min_rank)
+            .filter_map(|(_, origin, symbol)| {
                 if let Ok(symbol) = self.symbol_table.follow_alias(&symbol) {
                     Some((origin, symbol))
                 } else {
@@ -487,6 +572,19 @@ impl Lookup<EvalError> for Context {
             })
             .collect();
 
+        // drop candidates from the other namespace: this is what lets e.g. a property
+        // and a workbench share a name without ever being reported as ambiguous.
+        let found: Vec<_> = match namespace {
+            Some(namespace) => found
+                .into_iter()
+                .filter(|(_, symbol)| match symbol.namespace() {
+                    Some(ns) => ns == namespace,
+                    None => true,
+                })
+                .collect(),
+            None => found,
+        };
+
         // check for ambiguity in what's left
         match found.first() {
             Some((origin, first)) => {
@@ -519,10 +617,172 @@ impl Lookup<EvalError> for Context {
                     not_found = crate::mark!(NOT_FOUND_INTERIM)
                 );
 
-                Err(EvalError::SymbolNotFound(name.clone()))
+                match self.suggest_candidate(name) {
+                    Some(suggestion) => Err(EvalError::SymbolNotFoundSuggestion {
+                        name: name.clone(),
+                        suggestion,
+                    }),
+                    None => Err(EvalError::SymbolNotFound(name.clone())),
+                }
+            }
+        }
+    }
+
+    /// Find the identifier visible at `name`'s lookup site that is the closest typo-distance
+    /// match for its leaf identifier, for use in [`EvalError::SymbolNotFoundSuggestion`].
+    ///
+    /// Candidates are the leaf identifiers of the current module, the locals currently on
+    /// the stack and the properties of the current model. A candidate is only suggested if
+    /// its Levenshtein distance to the failed identifier is at most `max(1, len / 3)`; ties
+    /// are broken by shortest candidate, then lexical order.
+    fn suggest_candidate(&self, name: &QualifiedName) -> Option<Identifier> {
+        let failed = name.last()?.clone();
+
+        let module_children = match self.lookup(&self.stack.current_module_name()) {
+            Ok(module) => {
+                let mut ids = Vec::new();
+                let _ = module.with_children::<ResolveError>(|(id, _)| {
+                    ids.push(id.clone());
+                    Ok(())
+                });
+                ids
             }
+            Err(_) => Vec::new(),
+        };
+
+        let candidates = module_children
+            .into_iter()
+            .chain(self.stack.local_identifiers())
+            .chain(self.get_model().map(|model| model.property_ids()).unwrap_or_default())
+            .filter(|candidate| *candidate != failed);
+
+        let max_distance = std::cmp::max(1, failed.to_string().len() / 3);
+        candidates
+            .filter_map(|candidate| {
+                let distance = levenshtein_distance(&failed.to_string(), &candidate.to_string());
+                (distance <= max_distance).then_some((distance, candidate))
+            })
+            .min_by(|(d1, c1), (d2, c2)| {
+                d1.cmp(d2)
+                    .then_with(|| c1.to_string().len().cmp(&c2.to_string().len()))
+                    .then_with(|| c1.to_string().cmp(&c2.to_string()))
+            })
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+/// Levenshtein edit distance between two strings, used by [`Context::suggest_candidate`]
+/// to rank "did you mean ...?" candidates for an unresolved symbol.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let deletion = row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            let substitution = previous + usize::from(ca != cb);
+            previous = row[j + 1];
+            row[j + 1] = deletion.min(insertion).min(substitution);
         }
     }
+    row[b.len()]
+}
+
+/// Whether a lookup origin failing with `err` should just be dropped from the candidate
+/// list (another origin might still resolve the name) rather than aborting the whole
+/// lookup: a "not found" from one origin is expected noise, not a real failure.
+///
+/// `SymbolNotFoundSuggestion` must be included here alongside `SymbolNotFound`: the
+/// recursive `self.stack.lookup` -> `SymbolOrName::Name` branch in [`Context::lookup_filtered`]
+/// can return it, and treating it as a hard error would make a failed local-alias lookup
+/// abort resolution even when the module/workbench/global origins would have succeeded.
+fn is_benign_not_found(err: &EvalError) -> bool {
+    matches!(
+        err,
+        EvalError::SymbolNotFound(_)
+            | EvalError::SymbolNotFoundSuggestion { .. }
+            | EvalError::ResolveError(ResolveError::SymbolNotFound(_))
+            | EvalError::LocalNotFound(_)
+            | EvalError::ResolveError(ResolveError::ExternalPathNotFound(_))
+            | EvalError::ResolveError(ResolveError::NulHash)
+    )
+}
+
+#[test]
+fn levenshtein_distance_ranks_near_misses() {
+    assert_eq!(levenshtein_distance("circle", "circle"), 0);
+    assert_eq!(levenshtein_distance("circl", "circle"), 1);
+    assert_eq!(levenshtein_distance("cirlce", "circle"), 2);
+    assert_eq!(levenshtein_distance("circle", "sphere"), 5);
+}
+
+#[test]
+fn symbol_not_found_suggestion_is_benign() {
+    // A `SymbolNotFoundSuggestion` from a failed local-alias lookup must not abort the
+    // whole lookup: the module/workbench/global origins still get a chance to resolve it.
+    let err = EvalError::SymbolNotFoundSuggestion {
+        name: "circl".try_into().expect("valid name"),
+        suggestion: Identifier::no_ref("circle"),
+    };
+    assert!(is_benign_not_found(&err));
+    assert!(is_benign_not_found(&EvalError::SymbolNotFound(
+        "circl".try_into().expect("valid name")
+    )));
+}
+
+#[test]
+fn local_shadows_global_by_precedence_rank() {
+    let mut context = Context::default();
+    context.open(StackFrame::Source("test".into(), SymbolMap::default()));
+
+    let global = Symbol::new(
+        SymbolDefinition::Constant(Visibility::Public, Identifier::no_ref("x"), Value::Integer(1)),
+        None,
+    );
+    context.symbol_table.add_symbol(global).expect("insert global");
+
+    let local = Symbol::new(
+        SymbolDefinition::Constant(Visibility::Private, Identifier::no_ref("x"), Value::Integer(2)),
+        None,
+    );
+    context.stack.put_local(None, local).expect("insert local");
+
+    // `local` (rank 0) must shadow `global` (rank 3) rather than being reported as ambiguous.
+    let found = context
+        .lookup(&"x".try_into().expect("valid name"))
+        .expect("symbol found");
+    match &found.borrow().def {
+        SymbolDefinition::Constant(.., Value::Integer(value)) => assert_eq!(*value, 2),
+        def => panic!("expected local constant, got {def:?}"),
+    }
+}
+
+impl Lookup<EvalError> for Context {
+    fn lookup(&self, name: &QualifiedName) -> EvalResult<Symbol> {
+        self.lookup_filtered(name, None)
+    }
+
+    fn ambiguity_error(ambiguous: QualifiedName, _others: QualifiedNames) -> EvalError {
+        // `Context::lookup_within` is an inherent method (see above) that shadows the
+        // trait default of the same name, so this is never hit by `Context` itself: it
+        // only exists to satisfy the `Lookup` contract for generic callers.
+        EvalError::AmbiguousSymbol {
+            ambiguous,
+            others: Symbols::default(),
+        }
+    }
+
+    fn lookup_in_namespace(
+        &self,
+        name: &QualifiedName,
+        namespace: SymbolNamespace,
+    ) -> EvalResult<Symbol> {
+        self.lookup_filtered(name, Some(namespace))
+    }
 }
 
 /*
@@ -41,6 +41,17 @@ pub enum EvalError {
     #[error("Symbol {0} not found.")]
     SymbolNotFound(QualifiedName),
 
+    /// Symbol not found, but a close match was found among the candidates visible
+    /// at the lookup site (locals, properties of the current model and leaf symbols
+    /// of the current module).
+    #[error("unknown symbol '{name}'; did you mean '{suggestion}'?")]
+    SymbolNotFoundSuggestion {
+        /// The name that could not be resolved.
+        name: QualifiedName,
+        /// The closest matching candidate identifier.
+        suggestion: Identifier,
+    },
+
     /// Given symbol has not children which can be used.
     #[error("No symbols found to use in {0}")]
     NoSymbolsToUse(QualifiedName),
@@ -261,6 +272,11 @@ pub enum EvalError {
         /// where it was searched
         within: QualifiedName,
     },
+
+    /// [`SourceCache::rebuild`](crate::eval::SourceCache::rebuild) found a cycle in the
+    /// dependency graph between source files, given by their hashes.
+    #[error("Dependency cycle detected between source files: {0:?}")]
+    DependencyCycle(Vec<u64>),
 }
 
 /// Result type of any evaluation.
@@ -271,35 +271,42 @@ impl Eval for NestedItem {
     fn eval(&self, context: &mut Context) -> EvalResult<Value> {
         match &self {
             NestedItem::Call(call) => Ok(call.eval(context)?),
-            NestedItem::QualifiedName(name) => match &context.lookup(name)?.borrow().def {
-                SymbolDefinition::Constant(_, value) | SymbolDefinition::Argument(_, value) => {
-                    Ok(value.clone())
-                }
-                SymbolDefinition::Module(ns) => {
-                    Err(EvalError::UnexpectedNested("mod", ns.id.clone()))
-                }
-                SymbolDefinition::Workbench(w) => {
-                    Err(EvalError::UnexpectedNested(w.kind.as_str(), w.id.clone()))
-                }
-                SymbolDefinition::Function(f) => {
-                    Err(EvalError::UnexpectedNested("function", f.id.clone()))
-                }
-                SymbolDefinition::Builtin(bm) => {
-                    Err(EvalError::UnexpectedNested("builtin", bm.id.clone()))
-                }
-                SymbolDefinition::Alias(id, _) => {
-                    unreachable!("Unexpected alias {id} in expression")
-                }
-                SymbolDefinition::SourceFile(sf) => {
-                    unreachable!(
-                        "Unexpected source file {} in expression",
-                        sf.filename_as_str()
-                    )
-                }
-                SymbolDefinition::External(ns) => {
-                    unreachable!("Unexpected unload source file {} in expression", ns.id)
+            // a bare qualified name used as an expression operand always denotes a value,
+            // so restrict the lookup to `SymbolNamespace::Value`.
+            NestedItem::QualifiedName(name) => {
+                match &context
+                    .lookup_in_namespace(name, SymbolNamespace::Value)?
+                    .borrow()
+                    .def
+                {
+                    SymbolDefinition::Constant(_, value)
+                    | SymbolDefinition::Argument(_, value) => Ok(value.clone()),
+                    SymbolDefinition::Module(ns) => {
+                        Err(EvalError::UnexpectedNested("mod", ns.id.clone()))
+                    }
+                    SymbolDefinition::Workbench(w) => {
+                        Err(EvalError::UnexpectedNested(w.kind.as_str(), w.id.clone()))
+                    }
+                    SymbolDefinition::Function(f) => {
+                        Err(EvalError::UnexpectedNested("function", f.id.clone()))
+                    }
+                    SymbolDefinition::Builtin(bm) => {
+                        Err(EvalError::UnexpectedNested("builtin", bm.id.clone()))
+                    }
+                    SymbolDefinition::Alias(id, _) => {
+                        unreachable!("Unexpected alias {id} in expression")
+                    }
+                    SymbolDefinition::SourceFile(sf) => {
+                        unreachable!(
+                            "Unexpected source file {} in expression",
+                            sf.filename_as_str()
+                        )
+                    }
+                    SymbolDefinition::External(ns) => {
+                        unreachable!("Unexpected unload source file {} in expression", ns.id)
+                    }
                 }
-            },
+            }
             NestedItem::Body(body) => Ok(Value::from_single_model(body.eval(context)?)),
         }
     }
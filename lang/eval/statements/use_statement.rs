@@ -52,26 +52,26 @@ impl Eval<()> for UseStatement {
 
 impl Eval<()> for UseDeclaration {
     fn eval(&self, context: &mut Context) -> EvalResult<()> {
-        todo!()
-        /*
+        // `use` statements never target a nested symbol explicitly, so `within` is empty.
+        let within = QualifiedName::default();
         match &self {
             UseDeclaration::Use(visibility, name) => {
-                if let Err(err) = context.use_symbol(*visibility, name, None) {
+                if let Err(err) = context.use_symbol(*visibility, name, None, &within) {
                     context.error(name, err)?;
                 }
             }
             UseDeclaration::UseAll(visibility, name) => {
-                if let Err(err) = context.use_symbols_of(*visibility, name) {
+                if let Err(err) = context.use_symbols_of(*visibility, name, &within) {
                     context.error(name, err)?
                 }
             }
             UseDeclaration::UseAlias(visibility, name, alias) => {
-                if let Err(err) = context.use_symbol(*visibility, name, Some(alias.clone())) {
+                if let Err(err) = context.use_symbol(*visibility, name, Some(alias.clone()), &within)
+                {
                     context.error(name, err)?;
                 }
             }
         };
         Ok(())
-        */
     }
 }
@@ -4,7 +4,7 @@
 //! Source file cache
 
 use crate::{eval::*, rc::*, src_ref::*, syntax::*};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Register of loaded source files and their syntax trees.
 ///
@@ -13,6 +13,13 @@ use std::collections::HashMap;
 ///
 /// The *root model* (given at creation) will be stored but will only be accessible by hash and path
 /// but not by it's qualified name.
+///
+/// On top of that lookup table, the cache doubles as an incremental recompute system: each
+/// file records the hashes of the files it depends on (its imports) via
+/// [`SourceCache::set_dependencies`], and its resolved symbol table is memoized by
+/// [`SourceCache::rebuild`] keyed by that hash. Marking a file [`SourceCache::set_dirty`]
+/// and calling [`SourceCache::rebuild`] only recomputes that file and everything that
+/// (transitively) depends on it, reusing the memoized table for everything else.
 #[derive(Default)]
 pub struct SourceCache {
     /// External files read from search path.
@@ -27,6 +34,19 @@ pub struct SourceCache {
 
     /// Search paths.
     search_paths: Vec<std::path::PathBuf>,
+
+    /// Hashes of the files each file (by hash) depends on, as recorded by
+    /// [`SourceCache::set_dependencies`].
+    dependencies: HashMap<u64, HashSet<u64>>,
+    /// Hashes of files considered out of date and due for recompute by [`SourceCache::rebuild`].
+    dirty: HashSet<u64>,
+    /// Resolved symbol table for each up-to-date file, memoized by hash.
+    resolved: HashMap<u64, Rc<crate::resolve::SymbolTable>>,
+
+    /// [`LineIndex`] of each file, built lazily and memoized by hash (see
+    /// [`SourceCache::line_index`]) so repeated diagnostics for the same file don't
+    /// rescan its source.
+    line_indices: HashMap<u64, Rc<LineIndex>>,
 }
 
 impl SourceCache {
@@ -46,6 +66,10 @@ impl SourceCache {
             // root shall not be found by name
             by_name: HashMap::new(),
             search_paths: search_paths.to_vec(),
+            dependencies: HashMap::new(),
+            dirty: HashSet::new(),
+            resolved: HashMap::new(),
+            line_indices: HashMap::new(),
         }
     }
 
@@ -138,6 +162,125 @@ impl SourceCache {
     pub fn search_paths(&self) -> &Vec<std::path::PathBuf> {
         &self.search_paths
     }
+
+    /// Record the set of file hashes that the file with hash `hash` depends on (its imports).
+    ///
+    /// Replaces any dependency set previously recorded for `hash` and marks `hash` dirty,
+    /// since a changed dependency set invalidates whatever was memoized for it.
+    pub fn set_dependencies(&mut self, hash: u64, depends_on: impl IntoIterator<Item = u64>) {
+        self.dependencies.insert(hash, depends_on.into_iter().collect());
+        self.set_dirty(hash);
+    }
+
+    /// Mark the file with hash `hash` dirty, dropping its memoized symbol table.
+    ///
+    /// [`SourceCache::rebuild`] will recompute it, along with every file whose recorded
+    /// dependency set (transitively) contains `hash`.
+    pub fn set_dirty(&mut self, hash: u64) {
+        self.resolved.remove(&hash);
+        self.dirty.insert(hash);
+    }
+
+    /// Recompute the symbol table of every dirty file and everything that (transitively)
+    /// depends on it, reusing the memoized table of everything else.
+    ///
+    /// `resolve` is called once per recomputed file, in an order where all of a file's
+    /// dependencies have already been recomputed (or were already up to date). Returns the
+    /// hashes of the files that were actually recomputed, so a caller can patch only the
+    /// corresponding subtrees instead of rebuilding the whole model tree.
+    ///
+    /// Returns [`EvalError::DependencyCycle`] if the dependency graph contains a cycle
+    /// reachable from the dirty set, rather than looping forever.
+    pub fn rebuild(
+        &mut self,
+        mut resolve: impl FnMut(u64) -> EvalResult<crate::resolve::SymbolTable>,
+    ) -> EvalResult<Vec<u64>> {
+        // reverse-dependency map: for each hash, the hashes that depend on it
+        let mut dependents: HashMap<u64, HashSet<u64>> = HashMap::new();
+        for (&hash, deps) in &self.dependencies {
+            for &dep in deps {
+                dependents.entry(dep).or_default().insert(hash);
+            }
+        }
+
+        // every hash (transitively) affected by the dirty set, via BFS over `dependents`
+        let mut affected: HashSet<u64> = HashSet::new();
+        let mut worklist: Vec<u64> = self.dirty.iter().copied().collect();
+        while let Some(hash) = worklist.pop() {
+            if affected.insert(hash) {
+                if let Some(dependents) = dependents.get(&hash) {
+                    worklist.extend(dependents.iter().copied());
+                }
+            }
+        }
+
+        // process in reverse-topological order: a file is only recomputed once every
+        // dependency of its that is also affected has already been recomputed
+        let mut recomputed = Vec::new();
+        let mut remaining = affected.clone();
+        while !remaining.is_empty() {
+            let ready: Vec<u64> = remaining
+                .iter()
+                .copied()
+                .filter(|hash| {
+                    self.dependencies.get(hash).map_or(true, |deps| {
+                        deps.iter().all(|dep| !remaining.contains(dep))
+                    })
+                })
+                .collect();
+
+            if ready.is_empty() {
+                return Err(EvalError::DependencyCycle(remaining.into_iter().collect()));
+            }
+
+            for hash in ready {
+                let symbol_table = Rc::new(resolve(hash)?);
+                self.resolved.insert(hash, symbol_table);
+                remaining.remove(&hash);
+                recomputed.push(hash);
+            }
+        }
+
+        self.dirty.clear();
+        Ok(recomputed)
+    }
+
+    /// Memoized symbol table of the file with hash `hash`, if it has been resolved by
+    /// [`SourceCache::rebuild`] and hasn't been marked dirty since.
+    pub fn resolved(&self, hash: u64) -> Option<&Rc<crate::resolve::SymbolTable>> {
+        self.resolved.get(&hash)
+    }
+
+    /// [`LineIndex`] of the file with hash `hash`, built once and cached.
+    ///
+    /// Used to turn a [`SrcRef`] byte offset into a human-readable line/column when
+    /// rendering diagnostics or exporting, without rescanning the source on every call.
+    pub fn line_index(&mut self, hash: u64) -> EvalResult<Rc<LineIndex>> {
+        if let Some(index) = self.line_indices.get(&hash) {
+            return Ok(index.clone());
+        }
+        let index = Rc::new(LineIndex::new(&self.get_by_hash(hash)?.source));
+        self.line_indices.insert(hash, index.clone());
+        Ok(index)
+    }
+
+    /// Render `file:line:col` plus a caret-underlined source snippet for the position
+    /// of `referrer`, using the cached [`LineIndex`] of that position's file.
+    pub fn location_str(&mut self, referrer: &impl SrcReferrer) -> EvalResult<String> {
+        let src_ref = referrer.src_ref();
+        let hash = src_ref.source_hash();
+        let source_file = self.get_by_hash(hash)?;
+        let offset = src_ref.0.as_ref().map(|inner| inner.range.start).unwrap_or(0);
+        let (line, col) = self.line_index(hash)?.offset_to_line_col(offset);
+        let snippet = source_file.get_line(line as usize - 1).unwrap_or_default();
+
+        Ok(format!(
+            "{filename}:{line}:{col}\n{snippet}\n{caret:>width$}",
+            filename = source_file.filename_as_str(),
+            caret = "^",
+            width = col as usize,
+        ))
+    }
 }
 
 /// Trait that can fetch for a file by it's hash value.
@@ -157,6 +300,59 @@ impl GetSourceByHash for SourceCache {
     }
 }
 
+#[test]
+fn rebuild_recomputes_only_dirty_files_and_their_dependents() {
+    let root = Rc::new(SourceFile {
+        hash: 1,
+        ..Default::default()
+    });
+    let mut cache = SourceCache::new(root, &[]);
+
+    // dependency chain: 3 depends on 2, 2 depends on 1
+    cache.set_dependencies(2, [1]);
+    cache.set_dependencies(3, [2]);
+
+    let recomputed = cache
+        .rebuild(|_hash| Ok(crate::resolve::SymbolTable::default()))
+        .expect("no cycle");
+    let mut recomputed = recomputed;
+    recomputed.sort();
+    // 1 itself was never marked dirty, only 2 and 3 were (by `set_dependencies`)
+    assert_eq!(recomputed, vec![2, 3]);
+
+    // nothing is dirty anymore, so a second rebuild recomputes nothing
+    let recomputed = cache
+        .rebuild(|_hash| Ok(crate::resolve::SymbolTable::default()))
+        .expect("no cycle");
+    assert!(recomputed.is_empty());
+
+    // marking the root dirty must also recompute everything that transitively depends on it
+    cache.set_dirty(1);
+    let mut recomputed = cache
+        .rebuild(|_hash| Ok(crate::resolve::SymbolTable::default()))
+        .expect("no cycle");
+    recomputed.sort();
+    assert_eq!(recomputed, vec![1, 2, 3]);
+}
+
+#[test]
+fn rebuild_reports_dependency_cycle() {
+    let root = Rc::new(SourceFile {
+        hash: 1,
+        ..Default::default()
+    });
+    let mut cache = SourceCache::new(root, &[]);
+
+    // 1 depends on 2 and 2 depends on 1: no file is ever ready
+    cache.set_dependencies(1, [2]);
+    cache.set_dependencies(2, [1]);
+
+    assert!(matches!(
+        cache.rebuild(|_hash| Ok(crate::resolve::SymbolTable::default())),
+        Err(EvalError::DependencyCycle(..))
+    ));
+}
+
 impl std::fmt::Display for SourceCache {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (index, source_file) in self.source_files.iter().enumerate() {
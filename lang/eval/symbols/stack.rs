@@ -57,6 +57,41 @@ impl Stack {
         Err(EvalError::LocalStackEmpty(id))
     }
 
+    /// Like [`Stack::put_local`] but for a binding introduced by a `use` statement.
+    ///
+    /// An explicit `use foo::bar;`/`use foo::bar as baz;` replaces an earlier glob
+    /// import (`use foo::*;`) of the same name, while two glob imports that disagree
+    /// on a name are merely recorded as a conflict (see [`SymbolMap::insert_imported`])
+    /// rather than one silently overwriting the other.
+    pub fn put_imported_local(&mut self, id: Option<Identifier>, symbol: Symbol) -> EvalResult<()> {
+        let id = if let Some(id) = id { id } else { symbol.id() };
+        for (pos, frame) in self.0.iter_mut().rev().enumerate() {
+            match frame {
+                StackFrame::Source(_, locals)
+                | StackFrame::Workbench(_, _, locals)
+                | StackFrame::Init(locals)
+                | StackFrame::Body(locals)
+                | StackFrame::Module(_, locals)
+                | StackFrame::Function(locals) => {
+                    locals.insert_imported(id.clone(), symbol);
+                    log::trace!("Local Stack:\n{self}");
+                    return Ok(());
+                }
+                StackFrame::Call {
+                    symbol: _,
+                    args: _,
+                    src_ref: _,
+                } => {
+                    // RULE: top call frame is transparent on stack
+                    if pos > 0 {
+                        return Err(EvalError::WrongStackFrame(id, "call"));
+                    }
+                }
+            }
+        }
+        Err(EvalError::LocalStackEmpty(id))
+    }
+
     fn current_workbench_id(&self) -> Option<&Identifier> {
         self.0.iter().rev().find_map(|frame| {
             if let StackFrame::Workbench(_, id, _) = frame {
@@ -144,6 +179,28 @@ impl Stack {
     pub(crate) fn current_symbol(&self) -> Option<Symbol> {
         self.0.iter().rev().find_map(|frame| frame.symbol())
     }
+
+    /// Identifiers of all locals currently visible on the stack (innermost scope first),
+    /// stopping at the nearest enclosing module/call boundary, same as [`Stack::fetch`].
+    pub(crate) fn local_identifiers(&self) -> Vec<Identifier> {
+        let mut ids = Vec::new();
+        for (n, frame) in self.0.iter().rev().enumerate() {
+            match frame {
+                StackFrame::Source(_, locals)
+                | StackFrame::Body(locals)
+                | StackFrame::Workbench(_, _, locals)
+                | StackFrame::Init(locals)
+                | StackFrame::Function(locals) => ids.extend(locals.keys().cloned()),
+                StackFrame::Module(_, _) => break,
+                StackFrame::Call { .. } => {
+                    if n > 0 {
+                        break;
+                    }
+                }
+            }
+        }
+        ids
+    }
 }
 
 impl Locals for Stack {
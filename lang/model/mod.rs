@@ -214,6 +214,15 @@ impl Model {
         self.borrow().element.get_property(id).cloned()
     }
 
+    /// Ids of all properties of this model, or an empty list if it has none
+    /// (e.g. a group or builtin workpiece).
+    pub fn property_ids(&self) -> Vec<Identifier> {
+        match &self.borrow().element {
+            Element::Workpiece(workpiece) => workpiece.properties.keys().cloned().collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Add a new property to the model.
     pub fn add_property(&mut self, id: Identifier, value: Value) {
         self.borrow_mut()
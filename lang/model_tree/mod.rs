@@ -7,17 +7,27 @@ pub mod attribute;
 pub mod element;
 pub mod iter;
 pub mod model;
+pub mod model_node;
+pub mod model_node_builder;
+pub mod model_nodes;
 pub mod object;
 pub mod operation;
+pub mod origin;
 pub mod output;
 pub mod render;
+pub mod serialize;
 
 pub use attribute::*;
 pub use element::*;
 pub use iter::*;
 pub use model::*;
+pub use model_node::*;
+pub use model_node_builder::*;
+pub use model_nodes::*;
 pub use object::*;
 pub use operation::*;
+pub use origin::*;
+pub use serialize::*;
 
 pub use output::*;
 
@@ -1,4 +1,4 @@
-// Copyright © 2024 The µcad authors <info@ucad.xyz>
+// Copyright © 2024-2025 The µcad authors <info@ucad.xyz>
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 //! Model node builder.
@@ -8,20 +8,21 @@ use std::rc::Rc;
 use microcad_core::{Geometry2D, Geometry3D};
 
 use crate::{
-    eval::{Context, EvalResult},
+    eval::EvalResult,
     model_tree::*,
     src_ref::{Refer, SrcRef},
     syntax::Identifier,
     value::Value,
 };
 
+/// A builder pattern to build model nodes.
+#[derive(Default)]
 pub struct ModelNodeBuilder {
     inner: ModelNodeInner,
+    /// Properties to add to the model node if it is an [`Object`].
     pub properties: ObjectProperties,
+    /// Children to add to this node.
     pub children: ModelNodes,
-
-    output_type: ModelNodeOutputType,
-    context: Option<Context>,
 }
 
 /// ModelNodeBuilder constructors.
@@ -29,26 +30,28 @@ pub struct ModelNodeBuilder {
 /// All methods in this `impl` block are used to create a new model builder with a specific [`Element`] type.
 impl ModelNodeBuilder {
     /// Create a new object from a body `{ ... }`.
-    fn new_object_body() -> Self {
+    pub fn new_object_body() -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::none(Element::Object(Object::default()))),
-            output_type: ModelNodeOutputType::NotDetermined,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
+        }
+    }
+
+    /// Create a new children placeholder.
+    pub fn new_children_placeholder() -> Self {
+        Self {
+            inner: ModelNodeInner::new(Refer::none(Element::ChildrenPlaceholder)),
+            ..Default::default()
         }
     }
 
     /// Create a new 2D object.
     ///
     /// This function is used when a call to a sketch is evaluated.
-    fn new_2d_object() -> Self {
+    pub fn new_2d_object() -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::none(Element::Object(Object::default()))),
-            output_type: ModelNodeOutputType::Geometry2D,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
         }
     }
 
@@ -58,10 +61,7 @@ impl ModelNodeBuilder {
     pub fn new_3d_object() -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::none(Element::Object(Object::default()))),
-            output_type: ModelNodeOutputType::Geometry3D,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
         }
     }
 
@@ -69,10 +69,7 @@ impl ModelNodeBuilder {
     pub fn new_2d_primitive(geometry: std::rc::Rc<Geometry2D>) -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::none(Element::Primitive2D(geometry))),
-            output_type: ModelNodeOutputType::Geometry2D,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
         }
     }
 
@@ -80,10 +77,7 @@ impl ModelNodeBuilder {
     pub fn new_3d_primitive(geometry: std::rc::Rc<Geometry3D>) -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::none(Element::Primitive3D(geometry))),
-            output_type: ModelNodeOutputType::Geometry3D,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
         }
     }
 
@@ -91,10 +85,7 @@ impl ModelNodeBuilder {
     pub fn new_transform(transform: AffineTransform, src_ref: SrcRef) -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::new(Element::Transform(transform), src_ref)),
-            output_type: ModelNodeOutputType::NotDetermined,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
         }
     }
 
@@ -102,92 +93,19 @@ impl ModelNodeBuilder {
     pub fn new_operation<T: Operation + 'static>(operation: T, src_ref: SrcRef) -> Self {
         Self {
             inner: ModelNodeInner::new(Refer::new(Element::Operation(Rc::new(operation)), src_ref)),
-            output_type: ModelNodeOutputType::NotDetermined,
-            properties: ObjectProperties::default(),
-            children: ModelNodes::default(),
-            context: None,
+            ..Default::default()
         }
     }
 }
 
 impl ModelNodeBuilder {
-    /// Determine the output type of this node by some child node.
-    ///
-    /// TODO: Replace `panic!` with context warnings.
-    pub fn determine_output_type(&self, child: &ModelNode) -> EvalResult<ModelNodeOutputType> {
-        match child.output_type() {
-            ModelNodeOutputType::NotDetermined => {
-                panic!("Child node's output type must have been determined")
-            }
-            ModelNodeOutputType::Invalid => {
-                panic!("Child node's output type is invalid.")
-            }
-            _ => {}
-        }
-
-        match self.output_type {
-            ModelNodeOutputType::NotDetermined => {
-                // Determine nodes output type by child output type.
-            }
-            ModelNodeOutputType::Geometry2D => {
-                if child.output_type() != self.output_type {
-                    panic!("Cannot nest a 2D geometry in a 3D geometry node.")
-                }
-            }
-            ModelNodeOutputType::Geometry3D => {
-                if child.output_type() != self.output_type {
-                    panic!("Cannot nest a 3D geometry in a 2D geometry node.")
-                }
-            }
-            ModelNodeOutputType::Invalid => {
-                panic!("Invalid output type.")
-            }
-        }
-
-        match self.inner.element() {
-            Element::ChildrenPlaceholder => panic!("A child placeholder cannot have children"),
-            Element::Transform(_) => {
-                if !self.inner.children().is_empty() {
-                    panic!("A transformation cannot have more than one child.")
-                }
-            }
-            Element::Operation(op) => {
-                if !self.inner.children().is_empty() {
-                    panic!("An operation cannot have more than one child.")
-                } else {
-                    return Ok(op.output_type(child));
-                }
-            }
-            _ => {}
-        }
-
-        Ok(child.output_type())
-    }
-
-    /// Add a new child to the node if it matches.
-    ///
-    /// Outputs a warning if the child node does not match and if a context is present.
-    pub fn add_child(mut self, child: ModelNode) -> EvalResult<Self> {
-        self.output_type = self.determine_output_type(&child)?;
-
-        self.children.push(child);
-        Ok(self)
-    }
-
     /// Add multiple children to the node if it matches.
-    pub fn add_children(&mut self, children: ModelNodes) -> EvalResult<&mut Self> {
-        if let Some(child) = children.first() {
-            //  TODO Check child's output type
-            //  self.output_type = self.determine_output_type(child)?;
-        }
-
-        for child in children.iter() {
-            self.children.push(child.clone());
-        }
-
+    pub fn add_children(mut self, mut children: ModelNodes) -> EvalResult<Self> {
+        self.children.append(&mut children);
         Ok(self)
     }
 
+    /// Set object properties.
     pub fn properties(mut self, properties: ObjectProperties) -> Self {
         self.properties = properties;
         self
@@ -200,17 +118,25 @@ impl ModelNodeBuilder {
     }
 
     /// Return true if the object has a property with `id`.
-    pub fn has_property(&mut self, id: &Identifier) -> bool {
+    pub fn has_property(&self, id: &Identifier) -> bool {
         self.properties.contains_key(id)
     }
 
+    /// Build a [`ModelNode`].
     pub fn build(mut self) -> ModelNode {
-        if let Element::Object(object) = self.inner.element_mut() {
+        if let Element::Object(object) = &mut self.inner.element.value {
             object.props = self.properties
         }
-        self.inner.output_type = self.output_type;
 
-        let node = ModelNode::new(self.inner);
-        node.append_children(self.children)
+        let node = ModelNode::new(self.inner.into());
+        node.append_children(self.children);
+        node.deduce_output_type();
+        node
+    }
+}
+
+impl std::fmt::Display for ModelNodeBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.properties)
     }
 }
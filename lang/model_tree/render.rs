@@ -9,7 +9,7 @@ use crate::model_tree::*;
 
 impl ModelNode {
     /// Return output type.
-    pub fn final_output_type(&self) -> ModelNodeOutputType {
+    pub fn output_type(&self) -> ModelNodeOutputType {
         self.borrow().output.model_node_output_type()
     }
 
@@ -26,7 +26,7 @@ impl ModelNode {
         };
         if output_type == ModelNodeOutputType::NotDetermined {
             let children = &self_.children;
-            output_type = children.deduce_output_type();
+            output_type = children.output_type();
         }
 
         self_.output = ModelNodeOutput::new(output_type.clone());
@@ -105,7 +105,7 @@ impl ModelNode {
             matches!(&node.borrow().element.value, Element::Operation(_))
         }
 
-        match self.final_output_type() {
+        match self.output_type() {
             ModelNodeOutputType::Geometry2D => {
                 let geometries = render_geometries_2d(self);
                 if !is_operation(self) {
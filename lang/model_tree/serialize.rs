@@ -0,0 +1,165 @@
+// Copyright © 2025 The µcad authors <info@ucad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Structured, serde-based model tree export and import.
+//!
+//! Replaces a flat, depth-prefixed debug dump with a proper tree of
+//! [`SerializedModel`] nodes that can be handed to any `serde` backend
+//! (YAML, JSON, ...) for inspection or diffing. This is a *read-only*
+//! snapshot: [`SerializedModel::to_model_node`] reconstructs the node
+//! *structure* (id, nesting) for tooling that only cares about the shape of
+//! the tree, but not a node's geometry, creator symbol or attributes, none
+//! of which are serde-representable (see [`SerializedModel::to_model_node`]
+//! for why). It cannot stand in for a render cache.
+
+use crate::{model_tree::*, resolve::FullyQualify, syntax::Identifier};
+
+/// The resolved output type of a [`ModelNode`], recomputed from its element
+/// tree rather than trusting the node's (possibly stale) render cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum SerializedOutputType {
+    /// The output type could not be determined.
+    #[default]
+    NotDetermined,
+    /// The node outputs a 2d geometry.
+    Geometry2D,
+    /// The node outputs a 3d geometry.
+    Geometry3D,
+    /// The node mixes 2d and 3d children, which is invalid.
+    InvalidMixed,
+}
+
+impl SerializedOutputType {
+    /// Merge this output type with a child's, the same way the model tree
+    /// itself accumulates an undetermined node's type from its children.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::NotDetermined, other) => other,
+            (this, Self::NotDetermined) => this,
+            (this, other) if this == other => this,
+            _ => Self::InvalidMixed,
+        }
+    }
+}
+
+impl From<&Element> for SerializedOutputType {
+    fn from(element: &Element) -> Self {
+        match element {
+            Element::Primitive2D(_) => Self::Geometry2D,
+            Element::Primitive3D(_) => Self::Geometry3D,
+            Element::Object(_) | Element::ChildrenPlaceholder | Element::Transform(_) => {
+                Self::NotDetermined
+            }
+            Element::Operation(_) => Self::NotDetermined,
+        }
+    }
+}
+
+/// The symbol and arguments a [`ModelNode`] was created from, see [`ModelNodeOrigin`].
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct SerializedOrigin {
+    /// Fully qualified name of the symbol that was called.
+    pub creator: String,
+    /// The arguments of that call, rendered as a one-line expression.
+    pub arguments: String,
+}
+
+impl From<&ModelNodeOrigin> for Option<SerializedOrigin> {
+    fn from(origin: &ModelNodeOrigin) -> Self {
+        origin.creator.as_ref().map(|creator| SerializedOrigin {
+            creator: creator.full_name().to_string(),
+            arguments: origin.arguments.to_string(),
+        })
+    }
+}
+
+/// A serializable snapshot of a [`ModelNode`] and its descendants.
+///
+/// Built by [`SerializedModel::from_model_node`], recursing over each node's
+/// `borrow().children` to assemble the nested tree, so the shape of the
+/// serialized tree matches the shape of the model tree itself.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct SerializedModel {
+    /// The node's id, if it was created by an assignment (`a = cube(50mm)`).
+    pub id: Option<String>,
+    /// The node's element kind, e.g. `Object`, `Primitive2D`, `Operation`.
+    pub element: String,
+    /// Attributes attached to the node, rendered for inspection.
+    ///
+    /// Attributes are not reconstructed by [`SerializedModel::to_model_node`]: several
+    /// variants (e.g. an exporter attribute) hold a `dyn Exporter` and cannot be
+    /// round-tripped through serde.
+    pub attributes: Vec<String>,
+    /// The symbol and arguments the node was created from, if any.
+    pub origin: Option<SerializedOrigin>,
+    /// The node's resolved output type.
+    pub output_type: SerializedOutputType,
+    /// Serialized children, in tree order.
+    pub children: Vec<SerializedModel>,
+}
+
+impl SerializedModel {
+    /// Snapshot `node` and all of its descendants.
+    pub fn from_model_node(node: &ModelNode) -> Self {
+        let node_ = node.borrow();
+
+        let mut output_type = SerializedOutputType::from(&node_.element.value);
+        let children: Vec<_> = node_
+            .children
+            .iter()
+            .map(Self::from_model_node)
+            .collect();
+        for child in &children {
+            output_type = output_type.merge(child.output_type);
+        }
+
+        let element_kind: &'static str = (&node_.element.value).into();
+
+        Self {
+            id: node_.id.as_ref().map(Identifier::to_string),
+            element: element_kind.to_string(),
+            attributes: node_.attributes.iter().map(|a| format!("{a:?}")).collect(),
+            origin: (&node_.origin).into(),
+            output_type,
+            children,
+        }
+    }
+
+    /// Reconstruct a [`ModelNode`] tree's *structure* from this snapshot.
+    ///
+    /// Only structure (id, nesting) survives the round trip: the element is
+    /// rebuilt as an empty [`Object`] placeholder, since the original geometry,
+    /// creator symbol and attributes are not serde-representable -- `creator`
+    /// is a [`Symbol`] that would have to be re-resolved against a symbol
+    /// table this snapshot doesn't carry, and `attributes` are stored as
+    /// `{:?}`-rendered strings with no parser back to the original type.
+    ///
+    /// `output_type` is *not* restored either, even though it is plain data:
+    /// doing so would mean constructing this node's output through the same
+    /// `ModelNode`/`render` machinery used during evaluation, and that
+    /// machinery is unavailable here by construction (this is a cold,
+    /// un-evaluated placeholder, not a rendered node). Re-deriving it by
+    /// other means would just be reimplementing that machinery a second time
+    /// for a single field, for no real benefit over `NotDetermined` in a
+    /// snapshot that was never going to be rendered anyway.
+    ///
+    /// The result cannot be rendered and must not be used as a substitute for
+    /// re-evaluating the source; it exists so tooling (e.g. a tree diff) can
+    /// load a previously exported snapshot without re-parsing the YAML/JSON
+    /// by hand. A full cache artifact capable of skipping re-evaluation is
+    /// out of reach for this data shape and would need its own design (at
+    /// minimum, a way to serialize geometry and to re-resolve `creator`
+    /// against a symbol table) -- this function intentionally does not
+    /// pretend otherwise.
+    pub fn to_model_node(&self) -> ModelNode {
+        let node = ModelNodeBuilder::new_object_body().build();
+
+        node.borrow_mut().id = self.id.as_deref().map(Identifier::no_ref);
+
+        for child in &self.children {
+            node.append(child.to_model_node());
+        }
+
+        node
+    }
+}
@@ -13,6 +13,20 @@ pub trait Lookup<E: std::error::Error = ResolveError> {
     /// Return an ambiguity error.
     fn ambiguity_error(ambiguous: QualifiedName, others: QualifiedNames) -> E;
 
+    /// Search a *symbol* by it's *qualified name*, restricted to the given [`SymbolNamespace`].
+    ///
+    /// A call/instantiation position should look up [`SymbolNamespace::Entity`] and an
+    /// expression operand should look up [`SymbolNamespace::Value`], so that e.g. a property
+    /// and a workbench of the same name never collide: candidates from the other namespace
+    /// are no longer considered, so they can't raise an ambiguity either.
+    ///
+    /// The default implementation ignores `namespace` and just calls [`Lookup::lookup`];
+    /// implementors that actually maintain several origins should override this.
+    fn lookup_in_namespace(&self, name: &QualifiedName, namespace: SymbolNamespace) -> Result<Symbol, E> {
+        let _ = namespace;
+        self.lookup(name)
+    }
+
     /// Search a *symbol* by it's *qualified name* **and** within the given *symbol*.
     ///
     /// # Arguments
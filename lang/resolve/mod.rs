@@ -26,6 +26,7 @@ mod resolve_context;
 mod resolve_error;
 mod sources;
 mod symbol;
+mod symbol_index;
 mod symbol_table;
 mod symbolize;
 
@@ -36,6 +37,7 @@ pub use resolve_context::*;
 pub use resolve_error::*;
 pub use sources::*;
 pub use symbol::*;
+pub use symbol_index::*;
 pub use symbol_table::*;
 
 use grant::*;
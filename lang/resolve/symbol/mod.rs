@@ -24,6 +24,15 @@ use crate::{builtin::*, rc::*, resolve::*, src_ref::*, syntax::*, ty::*, value::
 #[derive(Clone)]
 pub struct Symbol {
     visibility: std::cell::Cell<Visibility>,
+    /// `true` if this particular binding was brought into scope by a glob import
+    /// (`use foo::*;`) rather than an explicit `use foo::bar;`/alias or a real
+    /// definition. Set on the clone that's actually inserted into a symbol map or
+    /// the local stack, never on the original definition it was cloned from.
+    glob_import: std::cell::Cell<bool>,
+    /// Other glob-imported bindings that also claimed this binding's name, recorded
+    /// so [`Symbol::check_glob_conflicts`] can report them lazily (at lookup time)
+    /// instead of one glob import silently shadowing the other at insertion time.
+    glob_conflicts: std::cell::RefCell<Vec<Symbol>>,
     inner: RcMut<SymbolInner>,
 }
 
@@ -36,6 +45,8 @@ impl Symbol {
     pub fn new(def: SymbolDefinition, parent: Option<Symbol>) -> Self {
         Symbol {
             visibility: std::cell::Cell::new(def.visibility()),
+            glob_import: std::cell::Cell::new(false),
+            glob_conflicts: Default::default(),
             inner: RcMut::new(SymbolInner {
                 def,
                 parent,
@@ -56,6 +67,8 @@ impl Symbol {
     ) -> Self {
         Symbol {
             visibility: std::cell::Cell::new(visibility),
+            glob_import: std::cell::Cell::new(false),
+            glob_conflicts: Default::default(),
             inner: RcMut::new(SymbolInner {
                 def,
                 parent,
@@ -173,6 +186,54 @@ impl Symbol {
         cloned
     }
 
+    /// Clone this symbol, give the clone another visibility and mark it as having
+    /// arrived via a glob import (`use foo::*;`).
+    ///
+    /// Used by [`crate::eval::UseSymbol::use_symbols_of`] so a later explicit
+    /// `use foo::bar;` of the same name can still shadow it without error, while
+    /// two glob imports disagreeing on a name are only reported once looked up
+    /// (see [`Symbol::add_glob_conflict`]).
+    pub(crate) fn clone_as_glob_import(&self, visibility: Visibility) -> Self {
+        let cloned = self.clone_with_visibility(visibility);
+        cloned.glob_import.set(true);
+        cloned
+    }
+
+    /// `true` if this binding arrived via a glob import rather than an explicit one.
+    pub(crate) fn is_glob_import(&self) -> bool {
+        self.glob_import.get()
+    }
+
+    /// Record another glob-imported binding that also claims this symbol's name.
+    pub(crate) fn add_glob_conflict(&self, other: Symbol) {
+        self.glob_conflicts.borrow_mut().push(other);
+    }
+
+    /// Turn any recorded glob-import conflicts (see [`Symbol::add_glob_conflict`])
+    /// into a [`ResolveError::AmbiguousSymbol`], or return this symbol unchanged if
+    /// there are none. Called wherever a lookup resolves to a symbol, so ambiguity
+    /// between two `use foo::*;` imports only surfaces when the shared name is
+    /// actually referenced.
+    pub(crate) fn check_glob_conflicts(&self, name: &QualifiedName) -> ResolveResult<Symbol> {
+        let conflicts = self.glob_conflicts.borrow();
+        if conflicts.is_empty() {
+            Ok(self.clone())
+        } else {
+            Err(ResolveError::AmbiguousSymbol(
+                name.clone(),
+                std::iter::once(self.full_name())
+                    .chain(conflicts.iter().map(Symbol::full_name))
+                    .collect(),
+            ))
+        }
+    }
+
+    /// Insert a symbol brought into scope by a `use` statement as a child of this
+    /// symbol, honoring glob-import shadowing (see [`SymbolMap::insert_imported`]).
+    pub(crate) fn insert_imported_child(&self, id: Identifier, symbol: Symbol) {
+        self.inner.borrow_mut().children.insert_imported(id, symbol);
+    }
+
     /// Return the internal *id* of this symbol.
     pub(crate) fn id(&self) -> Identifier {
         self.inner.borrow().def.id()
@@ -185,7 +246,7 @@ impl Symbol {
         self.inner.borrow().children.get(id).cloned()
     }
 
-    fn is_deleted(&self) -> bool {
+    pub(crate) fn is_deleted(&self) -> bool {
         self.visibility.get() == Visibility::Deleted
     }
 
@@ -219,7 +280,7 @@ impl Symbol {
             if let Some(child) = self.get(first) {
                 if name.is_single_identifier() && !child.is_deleted() {
                     log::trace!("Found {name:?} in {:?}", self.full_name());
-                    Ok(child.clone())
+                    child.check_glob_conflicts(name)
                 } else {
                     let name = &name.remove_first();
                     child.search(name)
@@ -310,6 +371,14 @@ impl Symbol {
         f(&mut self.inner.borrow_mut().def)
     }
 
+    /// Resolution namespace of this symbol (see [`SymbolNamespace`]).
+    ///
+    /// `None` for links (`Alias`/`UseAll`) that haven't been followed yet, since
+    /// they stand in for whatever namespace the symbol they point to lives in.
+    pub fn namespace(&self) -> Option<SymbolNamespace> {
+        self.with_def(SymbolDefinition::namespace)
+    }
+
     pub(super) fn is_resolvable(&self) -> bool {
         matches!(
             self.inner.borrow().def,
@@ -577,6 +646,8 @@ impl Default for Symbol {
     fn default() -> Self {
         Self {
             visibility: std::cell::Cell::new(Visibility::default()),
+            glob_import: std::cell::Cell::new(false),
+            glob_conflicts: Default::default(),
             inner: RcMut::new(Default::default()),
         }
     }
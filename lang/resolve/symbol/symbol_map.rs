@@ -73,7 +73,7 @@ impl SymbolMap {
         if let Some(symbol) = self.get(&id) {
             if leftover.is_empty() {
                 log::trace!("Fetched {name:?} from symbol map");
-                Ok(symbol.clone())
+                symbol.check_glob_conflicts(name)
             } else {
                 symbol.search(&leftover)
             }
@@ -82,6 +82,41 @@ impl SymbolMap {
         }
     }
 
+    /// Insert a symbol brought into scope by a `use` statement, honoring glob-import
+    /// shadowing rules.
+    ///
+    /// An explicit `use foo::bar;`/`use foo::bar as baz;` always wins over (and silently
+    /// replaces) an earlier glob import (`use foo::*;`) of the same name. Two glob imports
+    /// that disagree on a name are *not* rejected here: the first one stays in place and
+    /// the second is recorded as a conflict on it, so that looking the name up later
+    /// reports [`ResolveError::AmbiguousSymbol`] instead of one glob import silently
+    /// shadowing the other.
+    ///
+    /// Two *explicit* imports of the same id (or an explicit import of an id that's already
+    /// a real definition/earlier explicit import) are not silently resolved either way:
+    /// the second one is recorded as a conflict on the first, exactly like two colliding
+    /// glob imports, so it surfaces as [`ResolveError::AmbiguousSymbol`] if the name is
+    /// ever looked up instead of either import silently winning.
+    pub(crate) fn insert_imported(&mut self, id: Identifier, symbol: Symbol) {
+        let conflict = match self.get(&id) {
+            None => None,
+            Some(existing) if !existing.is_glob_import() => {
+                if symbol.is_glob_import() {
+                    return;
+                }
+                Some(existing.clone())
+            }
+            Some(existing) if symbol.is_glob_import() => Some(existing.clone()),
+            Some(_) => None,
+        };
+        match conflict {
+            Some(existing) => existing.add_glob_conflict(symbol),
+            None => {
+                self.0.insert(id, symbol);
+            }
+        }
+    }
+
     fn merge_all<I>(iter: I) -> SymbolMap
     where
         I: IntoIterator<Item = SymbolMap>,
@@ -102,6 +137,61 @@ impl SymbolMap {
     }
 }
 
+#[cfg(test)]
+fn test_constant(id: &str, value: i64) -> Symbol {
+    Symbol::new(
+        SymbolDefinition::Constant(Visibility::Public, Identifier::no_ref(id), Value::Integer(value)),
+        None,
+    )
+}
+
+#[test]
+fn explicit_import_wins_over_glob_import() {
+    let mut map = SymbolMap::new();
+    let id = Identifier::no_ref("x");
+
+    map.insert_imported(id.clone(), test_constant("x", 1).clone_as_glob_import(Visibility::Public));
+    map.insert_imported(id.clone(), test_constant("x", 2));
+
+    let found = map.get(&id).expect("symbol present").check_glob_conflicts(&id.clone().into());
+    match &found.expect("no conflict").borrow().def {
+        SymbolDefinition::Constant(.., Value::Integer(value)) => assert_eq!(*value, 2),
+        def => panic!("expected explicit constant, got {def:?}"),
+    }
+}
+
+#[test]
+fn conflicting_glob_imports_are_ambiguous_at_lookup() {
+    let mut map = SymbolMap::new();
+    let id = Identifier::no_ref("x");
+
+    map.insert_imported(id.clone(), test_constant("x", 1).clone_as_glob_import(Visibility::Public));
+    map.insert_imported(id.clone(), test_constant("x", 2).clone_as_glob_import(Visibility::Public));
+
+    let found = map.get(&id).expect("symbol present");
+    assert!(matches!(
+        found.check_glob_conflicts(&id.into()),
+        Err(ResolveError::AmbiguousSymbol(..))
+    ));
+}
+
+#[test]
+fn conflicting_explicit_imports_are_ambiguous_at_lookup() {
+    let mut map = SymbolMap::new();
+    let id = Identifier::no_ref("x");
+
+    // two explicit `use`s of the same id must not have one silently drop the other --
+    // surface it the same way two colliding glob imports do.
+    map.insert_imported(id.clone(), test_constant("x", 1));
+    map.insert_imported(id.clone(), test_constant("x", 2));
+
+    let found = map.get(&id).expect("symbol present");
+    assert!(matches!(
+        found.check_glob_conflicts(&id.into()),
+        Err(ResolveError::AmbiguousSymbol(..))
+    ));
+}
+
 impl std::fmt::Display for SymbolMap {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (id, symbol) in self.0.iter() {
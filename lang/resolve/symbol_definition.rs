@@ -3,6 +3,19 @@
 
 use crate::{builtin::*, rc::*, syntax::*, value::*};
 
+/// Resolution namespace a [`Symbol`] lives in, mirroring Rust's separate value/type
+/// namespaces: a call/instantiation position looks up an [`SymbolNamespace::Entity`]
+/// while an expression operand looks up a [`SymbolNamespace::Value`], so a property
+/// and a workbench can share a name without [`crate::eval::EvalError::AmbiguousSymbol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolNamespace {
+    /// Something that can be used as a value: a constant, property or local.
+    Value,
+    /// Something that can be called/instantiated or qualified into: a workbench, module,
+    /// source file, function or builtin.
+    Entity,
+}
+
 /// Symbol definition
 #[derive(Debug, Clone)]
 pub enum SymbolDefinition {
@@ -44,6 +57,46 @@ impl SymbolDefinition {
             Self::Tester(id) => id.clone(),
         }
     }
+
+    /// Resolution namespace this definition is looked up in (see [`SymbolNamespace`]).
+    ///
+    /// Returns `None` for definitions that merely stand in for another symbol
+    /// (`Alias`, `UseAll`) and so match a lookup in either namespace.
+    pub fn namespace(&self) -> Option<SymbolNamespace> {
+        match self {
+            Self::Workbench(..) | Self::Module(..) | Self::SourceFile(..)
+            | Self::Function(..) | Self::Builtin(..) => Some(SymbolNamespace::Entity),
+            Self::Constant(..) | Self::Argument(..) => Some(SymbolNamespace::Value),
+            Self::Alias(..) | Self::UseAll(..) => None,
+            #[cfg(test)]
+            Self::Tester(..) => None,
+        }
+    }
+}
+
+#[test]
+fn function_and_builtin_are_entity_namespace() {
+    let function = SymbolDefinition::Function(Rc::new(FunctionDefinition {
+        visibility: Visibility::Public,
+        id: Identifier::no_ref("f"),
+        signature: FunctionSignature {
+            parameters: Default::default(),
+            return_type: None,
+            src_ref: Default::default(),
+        },
+        body: Body::default(),
+        src_ref: Default::default(),
+    }));
+    let builtin = SymbolDefinition::Builtin(Rc::new(Builtin {
+        id: Identifier::no_ref("g"),
+        parameters: None,
+        f: &|_, _, _| Ok(Value::None),
+    }));
+
+    // a call/instantiation position looks up `SymbolNamespace::Entity`, so a plain
+    // function or builtin call must be found there, not filtered out as `Value`.
+    assert_eq!(function.namespace(), Some(SymbolNamespace::Entity));
+    assert_eq!(builtin.namespace(), Some(SymbolNamespace::Entity));
 }
 
 impl std::fmt::Display for SymbolDefinition {
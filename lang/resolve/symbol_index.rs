@@ -0,0 +1,167 @@
+// Copyright © 2025 The µcad authors <info@ucad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use crate::resolve::*;
+
+/// A flattened index over a [`SymbolTable`]'s symbols for "go to symbol" style fuzzy
+/// search and autocomplete, analogous to a file-symbol index in an IDE.
+///
+/// Built once by [`SymbolTable::build_index`] and reused across queries, so an editor
+/// can offer completion/navigation without walking the whole symbol tree on every
+/// keystroke. There is no per-file update: rebuilding after a change walks the whole
+/// symbol table again.
+#[derive(Default)]
+pub struct SymbolIndex {
+    /// Searchable candidate strings paired with the symbol they resolve to: each
+    /// symbol contributes its bare identifier and, if different, its full `::`-separated path.
+    entries: Vec<(String, Symbol)>,
+}
+
+impl SymbolTable {
+    /// Build a [`SymbolIndex`] over every symbol currently reachable from this table.
+    ///
+    /// Recurses into every symbol's children, skipping [`Symbol::is_deleted`] ones
+    /// (aliases and glob imports folded away during resolving).
+    pub fn build_index(&self) -> SymbolIndex {
+        let mut entries = Vec::new();
+        self.values()
+            .iter()
+            .for_each(|symbol| SymbolIndex::collect(symbol, &mut entries));
+        SymbolIndex { entries }
+    }
+}
+
+impl SymbolIndex {
+    fn collect(symbol: &Symbol, entries: &mut Vec<(String, Symbol)>) {
+        if symbol.is_deleted() {
+            return;
+        }
+
+        let id = symbol.id().to_string();
+        let full_name = symbol.full_name().to_string();
+        entries.push((id.clone(), symbol.clone()));
+        if full_name != id {
+            entries.push((full_name, symbol.clone()));
+        }
+
+        let _ = symbol.with_children::<ResolveError>(|(_, child)| {
+            Self::collect(child, entries);
+            Ok(())
+        });
+    }
+
+    /// Rank-limited fuzzy search over this index's candidates.
+    ///
+    /// `query` is matched as a case-insensitive subsequence against each candidate
+    /// string (a symbol's bare identifier or its full `::`-separated path): a symbol matches
+    /// if every character of `query` appears, in order, somewhere in the candidate.
+    /// Matches are scored favoring contiguous runs, prefix matches and shorter
+    /// candidates (see [`fuzzy_score`]), with ties broken by [`FullyQualify::full_name`]
+    /// length, and only the best `limit` are returned.
+    pub fn search_fuzzy(&self, query: &str, limit: usize) -> Vec<Symbol> {
+        let mut scored: Vec<(i64, usize, Symbol)> = Vec::new();
+
+        for (candidate, symbol) in &self.entries {
+            let Some(score) = fuzzy_score(query, candidate) else {
+                continue;
+            };
+
+            match scored.iter_mut().find(|(_, _, existing)| existing == symbol) {
+                Some(entry) if entry.0 >= score => {}
+                Some(entry) => entry.0 = score,
+                None => {
+                    let full_len = symbol.full_name().to_string().chars().count();
+                    scored.push((score, full_len, symbol.clone()));
+                }
+            }
+        }
+
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, symbol)| symbol)
+            .collect()
+    }
+}
+
+/// Score a case-insensitive subsequence match of `query` within `candidate`, or
+/// `None` if `query`'s characters don't all appear, in order, within `candidate`.
+///
+/// Rewards contiguous runs and matches that start at the very beginning of
+/// `candidate` (a prefix match), and slightly prefers shorter candidates so a
+/// precise short id outranks a long qualified path that merely contains the same
+/// letters.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+
+    let mut qi = 0;
+    let mut run = 0i64;
+    let mut score = 0i64;
+    for (ci, &c) in candidate.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if c == query[qi] {
+            run += 1;
+            score += run;
+            if ci == qi {
+                // still matching as a prefix of `candidate`
+                score += 3;
+            }
+            qi += 1;
+        } else {
+            run = 0;
+        }
+    }
+
+    if qi < query.len() {
+        None
+    } else {
+        Some(score - candidate.len() as i64)
+    }
+}
+
+#[test]
+fn build_index_and_search_fuzzy_ranks_near_misses() {
+    use crate::value::Value;
+
+    let module = Symbol::new(
+        SymbolDefinition::Module(ModuleDefinition::new(Visibility::Public, Identifier::no_ref("math"))),
+        None,
+    );
+    let sin = Symbol::new(
+        SymbolDefinition::Constant(Visibility::Public, Identifier::no_ref("sine"), Value::Integer(1)),
+        None,
+    );
+    let cos = Symbol::new(
+        SymbolDefinition::Constant(Visibility::Public, Identifier::no_ref("cosine"), Value::Integer(2)),
+        None,
+    );
+    Symbol::add_child(&module, sin.clone());
+    Symbol::add_child(&module, cos.clone());
+
+    let mut table = SymbolTable::default();
+    table.add_symbol(module).expect("no conflict");
+
+    let index = table.build_index();
+
+    // `sine` also matches inside `cosine` as a subsequence, but the prefix match outranks it
+    let results = index.search_fuzzy("sine", 10);
+    assert_eq!(results.first(), Some(&sin));
+
+    // an exact bare id is found by its short id, not just the qualified path
+    let results = index.search_fuzzy("cosine", 10);
+    assert_eq!(results.first(), Some(&cos));
+
+    // `limit` caps the number of returned candidates
+    assert_eq!(index.search_fuzzy("s", 1).len(), 1);
+
+    // a query whose characters don't appear in order in any candidate matches nothing
+    assert!(index.search_fuzzy("zzz", 10).is_empty());
+}
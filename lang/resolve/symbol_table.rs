@@ -72,6 +72,54 @@ impl SymbolTable {
         Ok(ids)
     }
 
+    /// Compute the shortest qualified name by which `target` can be referred to from the
+    /// scope `from`, the inverse of [`SymbolTable::lookup_within`].
+    ///
+    /// Widens outward from `from` one enclosing scope at a time: the bare identifier is
+    /// tried first (covers `target` being directly visible, including nested child
+    /// modules reached through a multi-segment relative name), then each wider ancestor
+    /// scope of `from` in turn, down to the global root as an absolute fallback. A
+    /// candidate is only accepted once [`SymbolTable::lookup_within`] confirms it
+    /// actually resolves back to `target` itself (guarding against shadowing), which also
+    /// means ties between a relative and an aliased name are broken exactly the way
+    /// [`SymbolTable::lookup_within`] already breaks them.
+    ///
+    /// Returns `None` if `target` is deleted, or if it cannot be reached from any
+    /// ancestor of `from` within the capped search depth.
+    pub fn find_path(&self, target: &Symbol, from: &Option<Symbol>) -> Option<QualifiedName> {
+        /// Upper bound on how many enclosing scopes to climb before giving up, to avoid
+        /// looping forever on a pathological (e.g. cyclic) module graph.
+        const MAX_DEPTH: usize = 64;
+
+        if target.is_deleted() {
+            return None;
+        }
+
+        let target_path = target.full_name();
+        let mut scope = from.clone();
+
+        for _ in 0..=MAX_DEPTH {
+            let prefix = scope.as_ref().map(Symbol::full_name).unwrap_or_default();
+            if target_path.starts_with(prefix.as_slice()) {
+                let suffix = QualifiedName::no_ref(target_path[prefix.len()..].to_vec());
+                if !suffix.is_empty() {
+                    if let Ok(found) = self.lookup_within(&suffix, &scope) {
+                        if found == *target {
+                            return Some(suffix);
+                        }
+                    }
+                }
+            }
+
+            match &scope {
+                Some(symbol) => scope = symbol.get_parent(),
+                None => break,
+            }
+        }
+
+        None
+    }
+
     // Search recursively within symbol **and** in the symbol table (global)
     pub(super) fn lookup_within(
         &self,
@@ -147,3 +195,36 @@ impl std::fmt::Debug for SymbolTable {
         writeln!(f, "{:?}", self.symbol_map)
     }
 }
+
+#[test]
+fn find_path_computes_shortest_reachable_name() {
+    use crate::value::Value;
+
+    let module = Symbol::new(
+        SymbolDefinition::Module(ModuleDefinition::new(Visibility::Public, Identifier::no_ref("m"))),
+        None,
+    );
+    let constant = Symbol::new(
+        SymbolDefinition::Constant(Visibility::Public, Identifier::no_ref("x"), Value::Integer(42)),
+        None,
+    );
+    Symbol::add_child(&module, constant.clone());
+
+    let mut table = SymbolTable::default();
+    table.add_symbol(module.clone()).expect("no conflict");
+
+    // from the global scope, the constant is only reachable through its module
+    let path = table
+        .find_path(&constant, &None)
+        .expect("reachable from global scope");
+    assert_eq!(
+        path,
+        QualifiedName::no_ref(vec![Identifier::no_ref("m"), Identifier::no_ref("x")])
+    );
+
+    // from within the module itself, the constant is reachable by its bare name
+    let path = table
+        .find_path(&constant, &Some(module))
+        .expect("reachable from within module");
+    assert_eq!(path, QualifiedName::no_ref(vec![Identifier::no_ref("x")]));
+}
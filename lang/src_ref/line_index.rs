@@ -0,0 +1,96 @@
+// Copyright © 2025 The µcad authors <info@ucad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Byte offset <-> line/column conversion for a single source file.
+
+/// Precomputed newline positions within a source file, for fast conversion between
+/// a [`crate::src_ref::SrcRef`] byte offset and a human-readable line/column position
+/// when rendering diagnostics or exporting.
+///
+/// Built once per source file (see [`LineIndex::new`]) and reused for every lookup,
+/// instead of rescanning the source on every diagnostic.
+#[derive(Debug, Clone, Default)]
+pub struct LineIndex {
+    /// The indexed source, kept so columns can be counted in characters rather than
+    /// bytes and so a byte offset can be recovered from a line/column pair.
+    source: String,
+    /// Byte offset of each `\n` in `source`, in ascending order.
+    newlines: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Build a line index over `source`.
+    pub fn new(source: &str) -> Self {
+        Self {
+            newlines: source
+                .bytes()
+                .enumerate()
+                .filter_map(|(i, b)| (b == b'\n').then_some(i))
+                .collect(),
+            source: source.to_owned(),
+        }
+    }
+
+    /// Convert a byte `offset` into a `(line, column)` pair, both *1-based* to match
+    /// [`crate::src_ref::LineCol`].
+    ///
+    /// The column is counted in characters, not bytes, so it stays correct for
+    /// multi-byte UTF-8 source. An `offset` past the end of the source is clamped to
+    /// the last line.
+    pub fn offset_to_line_col(&self, offset: usize) -> (u32, u32) {
+        let offset = offset.min(self.source.len());
+        let newlines_before = self.newlines.partition_point(|&nl| nl < offset);
+        let line_start = newlines_before
+            .checked_sub(1)
+            .map(|i| self.newlines[i] + 1)
+            .unwrap_or(0);
+        let col = self.source[line_start..offset].chars().count() + 1;
+        (newlines_before as u32 + 1, col as u32)
+    }
+
+    /// Convert a *1-based* `(line, column)` pair (column in characters) back into a
+    /// byte offset, the inverse of [`LineIndex::offset_to_line_col`].
+    ///
+    /// Returns `None` if `line` or `column` is zero, or `line` is past the end of the
+    /// source. A `column` past the end of its line clamps to the line's length
+    /// (covering both a line with no trailing newline and one that does).
+    pub fn line_col_to_offset(&self, line: u32, col: u32) -> Option<usize> {
+        let line = (line as usize).checked_sub(1)?;
+        let col = (col as usize).checked_sub(1)?;
+
+        let line_start = match line.checked_sub(1) {
+            Some(previous) => self.newlines.get(previous)? + 1,
+            None => 0,
+        };
+        let line_end = self.newlines.get(line).copied().unwrap_or(self.source.len());
+        let line_str = self.source.get(line_start..line_end)?;
+
+        let byte_offset = line_str
+            .char_indices()
+            .nth(col)
+            .map(|(i, _)| i)
+            .unwrap_or(line_str.len());
+        Some(line_start + byte_offset)
+    }
+}
+
+#[test]
+fn test_line_index() {
+    let source = "fn α() {\n  β;\n}\nno trailing newline";
+    let index = LineIndex::new(source);
+
+    // 'β' starts the second line and is a multi-byte character.
+    let beta_offset = source.find('β').expect("present");
+    assert_eq!(index.offset_to_line_col(beta_offset), (2, 3));
+    assert_eq!(index.line_col_to_offset(2, 3), Some(beta_offset));
+
+    // offset past EOF clamps to the last line.
+    let (last_line, _) = index.offset_to_line_col(source.len() + 10);
+    assert_eq!(last_line, 4);
+
+    // the file's last line has no trailing newline.
+    assert_eq!(
+        index.line_col_to_offset(4, 100),
+        Some(source.len())
+    );
+}
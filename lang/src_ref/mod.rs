@@ -12,10 +12,12 @@
 //! - [`SrcReferrer`] is a trait which provides unified access to the [`SrcRef`] (e.g. implemented by [`Refer`].
 
 mod line_col;
+mod line_index;
 mod refer;
 mod src_referrer;
 
 pub use line_col::*;
+pub use line_index::*;
 pub use refer::*;
 pub use src_referrer::*;
 
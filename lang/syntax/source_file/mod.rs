@@ -3,7 +3,7 @@
 
 //! µcad source file representation
 
-use crate::{rc::*, resolve::*, src_ref::*, syntax::*};
+use crate::{parse::*, parser::Parser, rc::*, resolve::*, src_ref::*, syntax::*};
 
 /// µcad source file
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -68,6 +68,24 @@ impl SourceFile {
         self.source.lines().count()
     }
 
+    /// Parse `src` into a standalone, named source file.
+    ///
+    /// Used by [`crate::eval::Context::eval_fragment`] to turn one fragment of a REPL-style
+    /// session into a [`SourceFile`] of its own. The hash is derived from `name` rather than
+    /// `src`, so re-entering the exact same line in two fragments still yields two distinct
+    /// [`SrcRef`]s instead of colliding on the content hash.
+    pub fn parse_fragment(name: QualifiedName, src: &str) -> ParseResult<Self> {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        name.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mut source_file: Self =
+            Parser::parse_rule(crate::parser::Rule::source_file, src, hash)?;
+        source_file.name = name;
+        Ok(source_file)
+    }
+
     /// Resolve into SymbolNode
     pub fn resolve(&self, parent: Option<Symbol>) -> Symbol {
         Rc::new(self.clone()).resolve_rc(parent)
@@ -142,6 +160,28 @@ fn load_source_file() {
     }
 }
 
+#[test]
+fn parse_fragment_hashes_by_name_not_content() {
+    let a = SourceFile::parse_fragment(
+        QualifiedName::no_ref(vec![Identifier::no_ref("<fragment 0>")]),
+        "x = 1;",
+    )
+    .expect("valid fragment");
+    let b = SourceFile::parse_fragment(
+        QualifiedName::no_ref(vec![Identifier::no_ref("<fragment 1>")]),
+        "x = 1;",
+    )
+    .expect("valid fragment");
+
+    // same source text, different fragment name: hashes (and so SrcRefs) must differ so
+    // re-entering the exact same line in a REPL session doesn't collide.
+    assert_ne!(a.hash, b.hash);
+    assert_eq!(
+        a.name,
+        QualifiedName::no_ref(vec![Identifier::no_ref("<fragment 0>")])
+    );
+}
+
 #[test]
 fn load_source_file_wrong_location() {
     let source_file = SourceFile::load("I do not exist.µcad");
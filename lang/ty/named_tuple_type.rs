@@ -5,6 +5,64 @@
 
 use crate::{syntax::*, ty::*};
 
+/// A builtin (or third-party) semantic shape recognized by [`NamedTupleType`],
+/// e.g. `Vec3` (`x, y, z`) or `Color` (`r, g, b, a`).
+///
+/// Registering one (see [`NamedTupleType::register_semantic_type`]) lets a tuple
+/// matching its field names be pretty-printed under `name` and recognized by
+/// [`NamedTupleType::semantic_name`], without editing any `is_*`/[`Display`] code.
+#[derive(Debug, Clone)]
+pub struct SemanticTupleType {
+    /// Name used by [`Display`] and [`NamedTupleType::semantic_name`], e.g. `"Vec3"`.
+    pub name: &'static str,
+    /// Required field identifiers; the tuple must have exactly these, in any order.
+    pub fields: &'static [&'static str],
+    /// Required common type of all fields (e.g. `Type::scalar`), or `None` if fields may differ.
+    pub common_type: Option<fn() -> Type>,
+}
+
+impl SemanticTupleType {
+    /// Check if `tuple` matches this shape.
+    fn matches(&self, tuple: &NamedTupleType) -> bool {
+        if tuple.0.len() != self.fields.len() {
+            return false;
+        }
+        if !self
+            .fields
+            .iter()
+            .all(|field| tuple.0.contains_key(&Identifier::no_ref(field)))
+        {
+            return false;
+        }
+        match self.common_type {
+            Some(common_type) => tuple.common_type().is_some_and(|ty| ty == common_type()),
+            None => true,
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Registry of recognized semantic named-tuple shapes, consulted by
+    /// [`NamedTupleType::semantic_name`] and its [`Display`] impl.
+    static ref SEMANTIC_TUPLE_TYPES: std::sync::RwLock<Vec<SemanticTupleType>> = std::sync::RwLock::new(vec![
+        SemanticTupleType {
+            name: "Color",
+            fields: &["r", "g", "b", "a"],
+            common_type: Some(Type::scalar),
+        },
+        SemanticTupleType {
+            name: "Vec2",
+            fields: &["x", "y"],
+            common_type: Some(Type::scalar),
+        },
+        SemanticTupleType {
+            name: "Vec3",
+            fields: &["x", "y", "z"],
+            common_type: Some(Type::scalar),
+        },
+    ]);
+}
+
 /// Named tuple (e.g. `(n: Scalar, m: String)`)
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct NamedTupleType(pub std::collections::BTreeMap<Identifier, Type>);
@@ -51,26 +109,6 @@ impl NamedTupleType {
         ])
     }
 
-    /// Test if the named tuple has exactly the number of keys.
-    fn has_exact_keys(&self, keys: &[&str]) -> bool {
-        if self.0.len() != keys.len() {
-            return false;
-        }
-
-        for key in keys {
-            if !self.0.contains_key(&Identifier::no_ref(key)) {
-                return false;
-            }
-        }
-
-        true
-    }
-
-    /// Checks if the named tuple type only holds scalar values.
-    fn is_scalar_only(&self) -> bool {
-        self.common_type().is_some_and(|ty| ty == Type::scalar())
-    }
-
     /// Test if all fields have a common type.
     pub(crate) fn common_type(&self) -> Option<Type> {
         let types = self.0.values().cloned().collect::<Vec<_>>();
@@ -82,32 +120,60 @@ impl NamedTupleType {
         None
     }
 
-    /// Check if the named tuple is a [`Color`].
-    pub(crate) fn is_color(&self) -> bool {
-        self.is_scalar_only() && self.has_exact_keys(&["r", "g", "b", "a"])
+    /// Register an additional semantic tuple shape (e.g. `Quaternion` or a
+    /// third-party primitive's vector type) so it is recognized by
+    /// [`NamedTupleType::semantic_name`] and pretty-printed by [`Display`].
+    pub fn register_semantic_type(semantic: SemanticTupleType) {
+        SEMANTIC_TUPLE_TYPES
+            .write()
+            .expect("semantic tuple type registry lock poisoned")
+            .push(semantic);
     }
 
-    /// Check if the named tuple is a [`Vec2`].
-    pub(crate) fn is_vec2(&self) -> bool {
-        self.is_scalar_only() && self.has_exact_keys(&["x", "y"])
+    /// Name of the registered semantic shape this tuple matches (e.g. `"Vec3"`), if any.
+    pub fn semantic_name(&self) -> Option<&'static str> {
+        SEMANTIC_TUPLE_TYPES
+            .read()
+            .expect("semantic tuple type registry lock poisoned")
+            .iter()
+            .find(|semantic| semantic.matches(self))
+            .map(|semantic| semantic.name)
     }
+}
 
-    /// Check if the named tuple is a [`Vec3`].
-    pub(crate) fn is_vec3(&self) -> bool {
-        self.is_scalar_only() && self.has_exact_keys(&["x", "y", "z"])
-    }
+#[test]
+fn semantic_name_recognizes_builtin_shapes() {
+    assert_eq!(NamedTupleType::new_vec2().semantic_name(), Some("Vec2"));
+    assert_eq!(NamedTupleType::new_vec3().semantic_name(), Some("Vec3"));
+    assert_eq!(NamedTupleType::new_color().semantic_name(), Some("Color"));
+
+    // a tuple with unrelated field names matches no registered shape
+    let other = NamedTupleType::new_from_slice(&[("width", Type::scalar()), ("height", Type::scalar())]);
+    assert_eq!(other.semantic_name(), None);
+}
+
+#[test]
+fn register_semantic_type_extends_recognition_without_editing_display() {
+    NamedTupleType::register_semantic_type(SemanticTupleType {
+        name: "Quaternion",
+        fields: &["qw", "qx", "qy", "qz"],
+        common_type: Some(Type::scalar),
+    });
+
+    let quaternion = NamedTupleType::new_from_slice(&[
+        ("qw", Type::scalar()),
+        ("qx", Type::scalar()),
+        ("qy", Type::scalar()),
+        ("qz", Type::scalar()),
+    ]);
+    assert_eq!(quaternion.semantic_name(), Some("Quaternion"));
+    assert_eq!(quaternion.to_string(), "Quaternion");
 }
 
 impl std::fmt::Display for NamedTupleType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        if self.is_color() {
-            return write!(f, "Color");
-        }
-        if self.is_vec2() {
-            return write!(f, "Vec2");
-        }
-        if self.is_vec3() {
-            return write!(f, "Vec3");
+        if let Some(name) = self.semantic_name() {
+            return write!(f, "{name}");
         }
 
         write!(f, "(")?;
@@ -1,8 +1,11 @@
 pub mod algorithm;
 pub mod primitive2d;
+mod registry;
 
 use ucad_parser::syntax_tree::{SyntaxNode, SyntaxNodeKind};
 
+pub use registry::*;
+
 pub enum NodeInner {
     /// Root Node
     Root,
@@ -19,36 +22,67 @@ pub enum NodeInner {
 
 pub type Node = rctree::Node<NodeInner>;
 
-pub struct TreeBuilder;
+/// Builds a [`Node`] tree from a [`SyntaxNode`], resolving each module call against a
+/// [`Registry`] of known primitives and algorithms instead of a hardcoded match.
+pub struct TreeBuilder {
+    registry: Registry,
+}
+
+impl Default for TreeBuilder {
+    fn default() -> Self {
+        Self {
+            registry: Registry::with_builtins(),
+        }
+    }
+}
 
 impl TreeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a factory for `name`, so downstream crates and standard-library
+    /// modules can add their own `primitive2d`/`algorithm` nodes without editing
+    /// this builder.
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        parameters: ParameterList,
+        factory: impl Fn(&ArgumentMap) -> Node + 'static,
+    ) {
+        self.registry.register(name, parameters, factory);
+    }
+
     pub fn from_syntax_node(syntax_node: SyntaxNode) -> Node {
+        Self::default().build(syntax_node)
+    }
+
+    pub fn build(&self, syntax_node: SyntaxNode) -> Node {
         let root = Node::new(NodeInner::Root);
 
-        Self::_from_syntax_node(root.clone(), syntax_node);
+        self._from_syntax_node(root.clone(), syntax_node);
         root
     }
 
-    fn _from_syntax_node(parent: Node, syntax_node: SyntaxNode) {
+    fn _from_syntax_node(&self, parent: Node, syntax_node: SyntaxNode) {
         for child in syntax_node.children() {
             let c = child.borrow();
 
             match c.kind() {
                 SyntaxNodeKind::ModuleNode(object_node) => {
-                    let mut node = None;
-                    match object_node.qualified_name().to_string().as_str() {
-                        "circle" => {
-                            // Todo: Parse arguments
-                            node = Some(crate::primitive2d::circle(5.0, 32));
-                        }
-                        "rectangle" => {
-                            // Todo: Create rectangle
+                    let name = object_node.qualified_name().to_string();
+                    // `ObjectNode` exposes `qualified_name()` (already relied on by the
+                    // pre-existing code above), but no accessor for its call arguments is
+                    // confirmed to exist on the upstream type, so calls are resolved with no
+                    // arguments for now and fall back entirely to registered defaults, same
+                    // as the hardcoded primitives this replaced.
+                    let call_args = CallArgumentList(Vec::new());
+                    match self.registry.build(&name, &call_args) {
+                        Ok(node) => {
+                            parent.append(node.clone());
+                            self._from_syntax_node(node, child.clone());
                         }
-                        _ => {}
-                    }
-                    if let Some(node) = node {
-                        parent.append(node.clone());
-                        Self::_from_syntax_node(node, child.clone());
+                        Err(err) => eprintln!("{err}"),
                     }
                 }
                 SyntaxNodeKind::Document(_) => {} // Ignore
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use crate::Node;
+
+/// One named, optionally defaulted argument a registered factory expects.
+pub struct Parameter {
+    pub name: &'static str,
+    pub default: Option<f64>,
+}
+
+/// The parameters a registered factory expects, in declaration order.
+pub struct ParameterList(pub Vec<Parameter>);
+
+/// Arguments as given at a call site: positional (matched by declaration order) or
+/// named.
+pub struct CallArgumentList(pub Vec<(Option<String>, f64)>);
+
+/// Call arguments resolved against a [`ParameterList`] by name, ready to hand to a
+/// registered factory.
+pub struct ArgumentMap(HashMap<&'static str, f64>);
+
+impl ArgumentMap {
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.0.get(name).copied()
+    }
+}
+
+#[derive(Debug)]
+pub enum TreeBuildError {
+    /// No factory is registered for this name.
+    UnknownName(String),
+    /// A call argument doesn't match any parameter, positional or named.
+    UnexpectedArgument(String),
+    /// A parameter has neither a default nor a matching call argument.
+    MissingArgument(&'static str),
+}
+
+impl std::fmt::Display for TreeBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownName(name) => write!(f, "unknown primitive or algorithm '{name}'"),
+            Self::UnexpectedArgument(name) => write!(f, "unexpected argument '{name}'"),
+            Self::MissingArgument(name) => write!(f, "missing argument '{name}'"),
+        }
+    }
+}
+
+impl ParameterList {
+    /// Resolve `call_args` against this parameter list the same way `lang::eval`'s
+    /// `ArgumentMatch` trait resolves a real call: named arguments bind by name first
+    /// and are removed from the pool of parameters still available, then the remaining
+    /// positional arguments bind to whatever's left over, in declaration order, and
+    /// finally anything still unbound falls back to its default.
+    ///
+    /// `tree` doesn't depend on `lang` — it builds a [`Node`] tree straight from
+    /// `ucad_parser`'s syntax tree, never touching `lang`'s `Value`/`Context` machinery
+    /// — so this re-derives the same named-then-positional-then-default match order for
+    /// plain f64 arguments instead of reusing `lang::eval` types that don't apply here.
+    /// Binding named arguments before positional ones (rather than in call-site order)
+    /// matters: `circle(radius = 2.0, 32.0)` must bind the positional `32.0` to
+    /// `segments`, not accidentally re-match `radius`.
+    pub fn get_matching_arguments(
+        &self,
+        call_args: &CallArgumentList,
+    ) -> Result<ArgumentMap, TreeBuildError> {
+        let mut resolved: HashMap<&'static str, f64> = HashMap::new();
+        let mut remaining: Vec<&Parameter> = self.0.iter().collect();
+
+        for (name, value) in call_args.0.iter().filter(|(name, _)| name.is_some()) {
+            let name = name.as_deref().expect("filtered to named arguments");
+            let index = remaining
+                .iter()
+                .position(|parameter| parameter.name == name)
+                .ok_or_else(|| TreeBuildError::UnexpectedArgument(name.to_owned()))?;
+            let parameter = remaining.remove(index);
+            resolved.insert(parameter.name, *value);
+        }
+
+        let mut positional = remaining.clone().into_iter();
+        for (_, value) in call_args.0.iter().filter(|(name, _)| name.is_none()) {
+            let parameter = positional
+                .next()
+                .ok_or_else(|| TreeBuildError::UnexpectedArgument("<positional>".into()))?;
+            resolved.insert(parameter.name, *value);
+            remaining.retain(|p| p.name != parameter.name);
+        }
+
+        for parameter in remaining {
+            match parameter.default {
+                Some(default) => {
+                    resolved.insert(parameter.name, default);
+                }
+                None => return Err(TreeBuildError::MissingArgument(parameter.name)),
+            }
+        }
+
+        Ok(ArgumentMap(resolved))
+    }
+}
+
+/// A factory registered under a qualified name: its expected parameters and the
+/// closure that turns resolved arguments into a [`Node`].
+pub struct RegisteredFactory {
+    parameters: ParameterList,
+    factory: Box<dyn Fn(&ArgumentMap) -> Node>,
+}
+
+/// Lookup table from qualified name to [`RegisteredFactory`], mirroring how a
+/// compiler keeps builtin definitions in a table rather than a giant match.
+///
+/// Populated with the crate's built-in `primitive2d`/`algorithm` nodes by
+/// [`Registry::with_builtins`]; downstream crates and standard-library modules can
+/// add their own via [`Registry::register`].
+#[derive(Default)]
+pub struct Registry(HashMap<&'static str, RegisteredFactory>);
+
+impl Registry {
+    /// A registry with nothing registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry pre-populated with this crate's built-in primitives and algorithms.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+
+        registry.register(
+            "circle",
+            ParameterList(vec![
+                Parameter { name: "radius", default: Some(1.0) },
+                Parameter { name: "segments", default: Some(32.0) },
+            ]),
+            |args| {
+                crate::primitive2d::circle(
+                    args.get("radius").unwrap_or(1.0),
+                    args.get("segments").unwrap_or(32.0) as usize,
+                )
+            },
+        );
+        registry.register(
+            "rectangle",
+            ParameterList(vec![
+                Parameter { name: "width", default: Some(1.0) },
+                Parameter { name: "height", default: Some(1.0) },
+            ]),
+            |args| {
+                crate::primitive2d::rectangle(
+                    args.get("width").unwrap_or(1.0),
+                    args.get("height").unwrap_or(1.0),
+                )
+            },
+        );
+        registry.register("difference", ParameterList(vec![]), |_| {
+            crate::algorithm::difference()
+        });
+
+        registry
+    }
+
+    /// Register a factory for `name`, so a call to it in the syntax tree builds a
+    /// node instead of producing [`TreeBuildError::UnknownName`].
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        parameters: ParameterList,
+        factory: impl Fn(&ArgumentMap) -> Node + 'static,
+    ) {
+        self.0.insert(
+            name,
+            RegisteredFactory {
+                parameters,
+                factory: Box::new(factory),
+            },
+        );
+    }
+
+    /// Build the node registered under `name` from `call_args`, or an error if `name`
+    /// is unregistered or `call_args` don't match its parameters.
+    pub fn build(&self, name: &str, call_args: &CallArgumentList) -> Result<Node, TreeBuildError> {
+        let registered = self
+            .0
+            .get(name)
+            .ok_or_else(|| TreeBuildError::UnknownName(name.to_owned()))?;
+        let args = registered.parameters.get_matching_arguments(call_args)?;
+        Ok((registered.factory)(&args))
+    }
+}
+
+#[test]
+fn get_matching_arguments_binds_named_before_positional() {
+    let parameters = ParameterList(vec![
+        Parameter { name: "radius", default: Some(1.0) },
+        Parameter { name: "segments", default: Some(32.0) },
+    ]);
+
+    // circle(radius = 2.0, 64.0): the bare positional `64.0` must bind to whichever
+    // parameter named binding didn't already claim (`segments`), not `radius` again.
+    let call_args = CallArgumentList(vec![(Some("radius".into()), 2.0), (None, 64.0)]);
+
+    let args = parameters.get_matching_arguments(&call_args).expect("valid match");
+    assert_eq!(args.get("radius"), Some(2.0));
+    assert_eq!(args.get("segments"), Some(64.0));
+}
+
+#[test]
+fn get_matching_arguments_falls_back_to_defaults() {
+    let parameters = ParameterList(vec![
+        Parameter { name: "width", default: Some(1.0) },
+        Parameter { name: "height", default: Some(1.0) },
+    ]);
+
+    let args = parameters
+        .get_matching_arguments(&CallArgumentList(vec![(None, 3.0)]))
+        .expect("valid match");
+    assert_eq!(args.get("width"), Some(3.0));
+    assert_eq!(args.get("height"), Some(1.0));
+}
+
+#[test]
+fn get_matching_arguments_rejects_unknown_name_and_missing_value() {
+    let parameters = ParameterList(vec![Parameter { name: "width", default: None }]);
+
+    assert!(matches!(
+        parameters.get_matching_arguments(&CallArgumentList(vec![(Some("depth".into()), 1.0)])),
+        Err(TreeBuildError::UnexpectedArgument(name)) if name == "depth"
+    ));
+    assert!(matches!(
+        parameters.get_matching_arguments(&CallArgumentList(vec![])),
+        Err(TreeBuildError::MissingArgument("width"))
+    ));
+}